@@ -3,19 +3,21 @@
 
 mod float_ord;
 mod name;
+mod random;
 mod task_pool_options;
 mod time;
 
 pub use bytemuck::{bytes_of, cast_slice, Pod, Zeroable};
 pub use float_ord::*;
 pub use name::*;
+pub use random::RandomSource;
 pub use task_pool_options::DefaultTaskPoolOptions;
 pub use time::*;
 
 pub mod prelude {
     //! The Bevy Core Prelude.
     #[doc(hidden)]
-    pub use crate::{DefaultTaskPoolOptions, Name, Time, Timer};
+    pub use crate::{DefaultTaskPoolOptions, Name, RandomSource, Time, Timer};
 }
 
 use bevy_app::prelude::*;
@@ -50,6 +52,7 @@ impl Plugin for CorePlugin {
 
         app.init_resource::<Time>()
             .init_resource::<FixedTimesteps>()
+            .init_resource::<RandomSource>()
             .register_type::<HashSet<String>>()
             .register_type::<Option<String>>()
             .register_type::<Entity>()
@@ -0,0 +1,145 @@
+use std::ops::Range;
+
+/// A seedable, deterministic pseudo-random number generator shared as a resource.
+///
+/// Every system drawing from the same [`RandomSource`] observes the same sequence of values for
+/// a given seed, which makes spawning, crowd variation and tests reproducible. To pick the seed,
+/// insert a [`RandomSource`] before adding the [`CorePlugin`](crate::CorePlugin):
+///
+/// ```
+/// # use bevy_core::RandomSource;
+/// # use bevy_app::App;
+/// App::new().insert_resource(RandomSource::from_seed(42));
+/// ```
+///
+/// This is not a cryptographically secure generator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RandomSource {
+    seed: u64,
+    state: u64,
+}
+
+impl Default for RandomSource {
+    fn default() -> Self {
+        RandomSource::from_seed(Self::DEFAULT_SEED)
+    }
+}
+
+impl RandomSource {
+    /// The seed used by [`RandomSource::default`].
+    pub const DEFAULT_SEED: u64 = 0x5eed;
+
+    /// Creates a new generator starting from the given `seed`.
+    pub fn from_seed(seed: u64) -> Self {
+        RandomSource { seed, state: seed }
+    }
+
+    /// Returns the seed this generator was last (re)seeded with.
+    #[inline]
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Restarts the sequence from the given `seed`.
+    pub fn reseed(&mut self, seed: u64) {
+        *self = RandomSource::from_seed(seed);
+    }
+
+    /// Returns the next value of the sequence, uniformly distributed over all `u64`s.
+    pub fn next_u64(&mut self) -> u64 {
+        // SplitMix64
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns the next value of the sequence, uniformly distributed over all `u32`s.
+    #[inline]
+    pub fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    /// Returns the next value of the sequence, uniformly distributed in `[0.0, 1.0)`.
+    #[inline]
+    pub fn next_f32(&mut self) -> f32 {
+        // use the 24 high bits, which fit exactly in an f32 mantissa
+        (self.next_u64() >> 40) as f32 / (1u32 << 24) as f32
+    }
+
+    /// Returns the next value of the sequence, uniformly distributed in `range`.
+    ///
+    /// Returns `range.start` if the range is empty.
+    pub fn next_range(&mut self, range: Range<f32>) -> f32 {
+        if range.end <= range.start {
+            return range.start;
+        }
+        let value = range.start + (range.end - range.start) * self.next_f32();
+        // guard against rounding up to the excluded end of the range
+        value.min(next_down(range.end))
+    }
+
+    /// Returns `true` with the given `probability`. A probability of `0.0` or less never returns
+    /// `true`, and one of `1.0` or more always does.
+    #[inline]
+    pub fn next_bool(&mut self, probability: f32) -> bool {
+        self.next_f32() < probability
+    }
+}
+
+/// Returns the largest `f32` smaller than the finite `value`.
+fn next_down(value: f32) -> f32 {
+    if value == 0.0 {
+        return -f32::from_bits(1);
+    }
+    let bits = value.to_bits();
+    if value > 0.0 {
+        f32::from_bits(bits - 1)
+    } else {
+        f32::from_bits(bits + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reseeding_repeats_the_sequence() {
+        let mut random = RandomSource::from_seed(7);
+        let first: Vec<u64> = (0..16).map(|_| random.next_u64()).collect();
+
+        random.reseed(7);
+        let second: Vec<u64> = (0..16).map(|_| random.next_u64()).collect();
+        assert_eq!(first, second);
+        assert_eq!(random.seed(), 7);
+
+        let mut other = RandomSource::from_seed(8);
+        let third: Vec<u64> = (0..16).map(|_| other.next_u64()).collect();
+        assert_ne!(first, third);
+    }
+
+    #[test]
+    fn values_stay_in_range() {
+        let mut random = RandomSource::default();
+        for _ in 0..1000 {
+            let value = random.next_f32();
+            assert!((0.0..1.0).contains(&value));
+
+            let value = random.next_range(-2.0..3.0);
+            assert!((-2.0..3.0).contains(&value));
+        }
+        assert_eq!(random.next_range(1.0..1.0), 1.0);
+
+        // at this magnitude the range only contains its start
+        for _ in 0..1000 {
+            assert_eq!(random.next_range(1e7..1e7 + 1.0), 1e7);
+        }
+        assert_eq!(next_down(1.0), 1.0 - f32::EPSILON / 2.0);
+        assert!(next_down(0.0) < 0.0);
+        assert!(next_down(-1.0) < -1.0);
+        assert!(!random.next_bool(0.0));
+        assert!(random.next_bool(1.0));
+    }
+}
@@ -1,6 +1,7 @@
 use crate::Transparent2d;
 use bevy_ecs::prelude::*;
 use bevy_render::{
+    camera::ExtractedCamera,
     render_graph::{Node, NodeRunError, RenderGraphContext, SlotInfo, SlotType},
     render_phase::{DrawFunctions, RenderPhase, TrackedRenderPass},
     render_resource::{LoadOp, Operations, RenderPassColorAttachment, RenderPassDescriptor},
@@ -9,8 +10,14 @@ use bevy_render::{
 };
 
 pub struct MainPass2dNode {
-    query:
-        QueryState<(&'static RenderPhase<Transparent2d>, &'static ViewTarget), With<ExtractedView>>,
+    query: QueryState<
+        (
+            &'static RenderPhase<Transparent2d>,
+            &'static ViewTarget,
+            Option<&'static ExtractedCamera>,
+        ),
+        With<ExtractedView>,
+    >,
 }
 
 impl MainPass2dNode {
@@ -39,7 +46,7 @@ impl Node for MainPass2dNode {
         world: &World,
     ) -> Result<(), NodeRunError> {
         let view_entity = graph.get_input_entity(Self::IN_VIEW)?;
-        let (transparent_phase, target) = self
+        let (transparent_phase, target, camera) = self
             .query
             .get_manual(world, view_entity)
             .expect("view entity should exist");
@@ -67,6 +74,9 @@ impl Node for MainPass2dNode {
 
         let mut draw_functions = draw_functions.write();
         let mut tracked_pass = TrackedRenderPass::new(render_pass);
+        if let Some(viewport) = camera.and_then(|camera| camera.physical_viewport.as_ref()) {
+            tracked_pass.set_camera_viewport(viewport);
+        }
         for item in transparent_phase.items.iter() {
             let draw_function = draw_functions.get_mut(item.draw_function).unwrap();
             draw_function.draw(world, &mut tracked_pass, view_entity, item);
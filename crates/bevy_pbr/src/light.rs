@@ -352,7 +352,7 @@ pub fn add_clusters(
             None => continue,
         };
         let clusters = Clusters::from_screen_size_and_z_slices(
-            UVec2::new(window.physical_width(), window.physical_height()),
+            camera.physical_viewport_rect(window).size,
             Z_SLICES,
         );
         commands.entity(entity).insert(clusters);
@@ -363,8 +363,12 @@ pub fn update_clusters(windows: Res<Windows>, mut views: Query<(&Camera, &mut Cl
     for (camera, mut clusters) in views.iter_mut() {
         let is_orthographic = camera.projection_matrix.w_axis.w == 1.0;
         let inverse_projection = camera.projection_matrix.inverse();
-        let window = windows.get(camera.window).unwrap();
-        let screen_size_u32 = UVec2::new(window.physical_width(), window.physical_height());
+        let window = match windows.get(camera.window) {
+            Some(window) => window,
+            None => continue,
+        };
+        // the projection covers the viewport, so the clusters do as well
+        let screen_size_u32 = camera.physical_viewport_rect(window).size;
         // Don't update clusters if screen size is 0.
         if screen_size_u32.x == 0 || screen_size_u32.y == 0 {
             continue;
@@ -130,7 +130,7 @@ pub struct GpuLights {
     directional_lights: [GpuDirectionalLight; MAX_DIRECTIONAL_LIGHTS],
     ambient_color: Vec4,
     cluster_dimensions: UVec4,
-    // xy are vec2<f32>(cluster_dimensions.xy) / vec2<f32>(view.width, view.height)
+    // xy are vec2<f32>(cluster_dimensions.xy) / view.viewport.zw
     // z is cluster_dimensions.z / log(far / near)
     // w is cluster_dimensions.z * log(near) / log(far / near)
     cluster_factors: Vec4,
@@ -691,8 +691,8 @@ pub fn prepare_lights(
             ambient_color: Vec4::from_slice(&ambient_light.color.as_linear_rgba_f32())
                 * ambient_light.brightness,
             cluster_factors: Vec4::new(
-                clusters.axis_slices.x as f32 / extracted_view.width as f32,
-                clusters.axis_slices.y as f32 / extracted_view.height as f32,
+                clusters.axis_slices.x as f32 / extracted_view.viewport.z as f32,
+                clusters.axis_slices.y as f32 / extracted_view.viewport.w as f32,
                 cluster_factors_zw.x,
                 cluster_factors_zw.y,
             ),
@@ -745,6 +745,12 @@ pub fn prepare_lights(
                         ExtractedView {
                             width: point_light_shadow_map.size as u32,
                             height: point_light_shadow_map.size as u32,
+                            viewport: UVec4::new(
+                                0,
+                                0,
+                                point_light_shadow_map.size as u32,
+                                point_light_shadow_map.size as u32,
+                            ),
                             transform: view_translation * *view_rotation,
                             projection: cube_face_projection,
                             near: POINT_LIGHT_NEAR_Z,
@@ -830,6 +836,12 @@ pub fn prepare_lights(
                         ExtractedView {
                             width: directional_light_shadow_map.size as u32,
                             height: directional_light_shadow_map.size as u32,
+                            viewport: UVec4::new(
+                                0,
+                                0,
+                                directional_light_shadow_map.size as u32,
+                                directional_light_shadow_map.size as u32,
+                            ),
                             transform: GlobalTransform::from_matrix(view.inverse()),
                             projection,
                             near: light.near,
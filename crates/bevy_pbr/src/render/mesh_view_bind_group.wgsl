@@ -7,6 +7,8 @@ struct View {
     far: f32;
     width: f32;
     height: f32;
+    // origin (xy) and size (zw) of the viewport in physical pixels
+    viewport: vec4<f32>;
 };
 
 struct PointLight {
@@ -40,7 +42,7 @@ struct Lights {
     ambient_color: vec4<f32>;
     // x/y/z dimensions
     cluster_dimensions: vec4<u32>;
-    // xy are vec2<f32>(cluster_dimensions.xy) / vec2<f32>(view.width, view.height)
+    // xy are vec2<f32>(cluster_dimensions.xy) / view.viewport.zw
     //
     // For perspective projections:
     // z is cluster_dimensions.z / log(far / near)
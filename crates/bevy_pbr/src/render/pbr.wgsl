@@ -556,7 +556,7 @@ fn fragment(in: FragmentInput) -> [[location(0)]] vec4<f32> {
             view.inverse_view[2].z,
             view.inverse_view[3].z
         ), in.world_position);
-        let cluster_index = fragment_cluster_index(in.frag_coord.xy, view_z, is_orthographic);
+        let cluster_index = fragment_cluster_index(in.frag_coord.xy - view.viewport.xy, view_z, is_orthographic);
         let offset_and_count = unpack_offset_and_count(cluster_index);
         for (var i: u32 = offset_and_count.offset; i < offset_and_count.offset + offset_and_count.count; i = i + 1u) {
             let light_id = get_light_id(i);
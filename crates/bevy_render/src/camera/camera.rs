@@ -8,10 +8,12 @@ use bevy_ecs::{
     reflect::ReflectComponent,
     system::{QuerySet, Res},
 };
-use bevy_math::{Mat4, Vec2, Vec3};
+use bevy_math::{Mat4, UVec2, Vec2, Vec3};
 use bevy_reflect::{Reflect, ReflectDeserialize};
 use bevy_transform::components::GlobalTransform;
-use bevy_window::{WindowCreated, WindowId, WindowResized, Windows};
+use bevy_window::{
+    Window, WindowCreated, WindowId, WindowResized, WindowScaleFactorChanged, Windows,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Component, Default, Debug, Reflect)]
@@ -25,6 +27,75 @@ pub struct Camera {
     pub depth_calculation: DepthCalculation,
     pub near: f32,
     pub far: f32,
+    /// Restricts rendering to a sub-region of the window. Renders to the whole window when `None`.
+    #[reflect(ignore)]
+    pub viewport: Option<Viewport>,
+    /// The physical pixel rect of [`Camera::viewport`], kept up to date with the window's size
+    /// and scale factor by [`camera_system`].
+    #[reflect(ignore)]
+    pub physical_viewport: Option<PhysicalViewport>,
+}
+
+/// A rendering region of a window.
+///
+/// When `relative` is true, `offset` and `size` are fractions of the window size (`0.0..=1.0`),
+/// otherwise they are in logical pixels. The origin is the top-left corner of the window.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Viewport {
+    pub offset: Vec2,
+    pub size: Vec2,
+    pub relative: bool,
+}
+
+impl Viewport {
+    /// Creates a viewport expressed as fractions of the window size.
+    pub fn relative(offset: Vec2, size: Vec2) -> Self {
+        Viewport {
+            offset,
+            size,
+            relative: true,
+        }
+    }
+
+    /// Creates a viewport expressed in logical pixels.
+    pub fn absolute(offset: Vec2, size: Vec2) -> Self {
+        Viewport {
+            offset,
+            size,
+            relative: false,
+        }
+    }
+
+    /// Computes the physical pixel rect of this viewport for a window of the given physical size
+    /// and scale factor. The rect is clamped to the window bounds, so it is empty when the viewport
+    /// lies outside of the window or is smaller than a pixel.
+    pub fn physical_rect(
+        &self,
+        physical_width: u32,
+        physical_height: u32,
+        scale_factor: f64,
+    ) -> PhysicalViewport {
+        let window_size = Vec2::new(physical_width as f32, physical_height as f32);
+        let (offset, size) = if self.relative {
+            (self.offset * window_size, self.size * window_size)
+        } else {
+            let scale_factor = scale_factor as f32;
+            (self.offset * scale_factor, self.size * scale_factor)
+        };
+        let min = offset.round().max(Vec2::ZERO).min(window_size);
+        let max = (offset + size).round().max(min).min(window_size);
+        PhysicalViewport {
+            origin: min.as_uvec2(),
+            size: (max - min).as_uvec2(),
+        }
+    }
+}
+
+/// A [`Viewport`] resolved to physical pixels of its window.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PhysicalViewport {
+    pub origin: UVec2,
+    pub size: UVec2,
 }
 
 #[derive(Debug, Clone, Copy, Reflect, Serialize, Deserialize)]
@@ -43,7 +114,26 @@ impl Default for DepthCalculation {
 }
 
 impl Camera {
+    /// Computes the physical pixel rect this camera renders to in the given window: its
+    /// [`Camera::viewport`], or the whole window when there is none.
+    pub fn physical_viewport_rect(&self, window: &Window) -> PhysicalViewport {
+        match self.viewport {
+            Some(viewport) => viewport.physical_rect(
+                window.physical_width(),
+                window.physical_height(),
+                window.scale_factor(),
+            ),
+            None => PhysicalViewport {
+                origin: UVec2::ZERO,
+                size: UVec2::new(window.physical_width(), window.physical_height()),
+            },
+        }
+    }
+
     /// Given a position in world space, use the camera to compute the screen space coordinates.
+    ///
+    /// Screen space is in logical pixels from the bottom-left corner of the window, like
+    /// [`Window::cursor_position`], and positions are mapped into the camera's viewport.
     pub fn world_to_screen(
         &self,
         windows: &Windows,
@@ -51,7 +141,14 @@ impl Camera {
         world_position: Vec3,
     ) -> Option<Vec2> {
         let window = windows.get(self.window)?;
-        let window_size = Vec2::new(window.width(), window.height());
+        let viewport = self.physical_viewport_rect(window);
+        let scale_factor = window.scale_factor() as f32;
+        let viewport_size = viewport.size.as_vec2() / scale_factor;
+        // viewports have a top-left origin, screen space a bottom-left one
+        let viewport_origin = Vec2::new(
+            viewport.origin.x as f32,
+            (window.physical_height() - viewport.origin.y - viewport.size.y) as f32,
+        ) / scale_factor;
         // Build a transform to convert from world to NDC using camera data
         let world_to_ndc: Mat4 =
             self.projection_matrix * camera_transform.compute_matrix().inverse();
@@ -61,7 +158,8 @@ impl Camera {
             return None;
         }
         // Once in NDC space, we can discard the z element and rescale x/y to fit the screen
-        let screen_space_coords = (ndc_space_coords.truncate() + Vec2::ONE) / 2.0 * window_size;
+        let screen_space_coords =
+            viewport_origin + (ndc_space_coords.truncate() + Vec2::ONE) / 2.0 * viewport_size;
         if !screen_space_coords.is_nan() {
             Some(screen_space_coords)
         } else {
@@ -74,6 +172,7 @@ impl Camera {
 pub fn camera_system<T: CameraProjection + Component>(
     mut window_resized_events: EventReader<WindowResized>,
     mut window_created_events: EventReader<WindowCreated>,
+    mut window_scale_factor_changed_events: EventReader<WindowScaleFactorChanged>,
     windows: Res<Windows>,
     mut queries: QuerySet<(
         QueryState<(Entity, &mut Camera, &mut T)>,
@@ -101,20 +200,173 @@ pub fn camera_system<T: CameraProjection + Component>(
         changed_window_ids.push(event.id);
     }
 
+    for event in window_scale_factor_changed_events.iter().rev() {
+        if changed_window_ids.contains(&event.id) {
+            continue;
+        }
+
+        changed_window_ids.push(event.id);
+    }
+
     let mut added_cameras = vec![];
     for entity in &mut queries.q1().iter() {
         added_cameras.push(entity);
     }
     for (entity, mut camera, mut camera_projection) in queries.q0().iter_mut() {
-        if let Some(window) = windows.get(camera.window) {
+        // minimized windows have no size to project onto. the resize on restore updates them
+        if let Some(window) = windows
+            .get(camera.window)
+            .filter(|window| !window.is_minimized())
+        {
+            // compare before writing so unchanged cameras are not marked as changed
+            let physical_viewport = camera.viewport.map(|viewport| {
+                viewport.physical_rect(
+                    window.physical_width(),
+                    window.physical_height(),
+                    window.scale_factor(),
+                )
+            });
+            let viewport_changed = camera.physical_viewport != physical_viewport;
+            if viewport_changed {
+                camera.physical_viewport = physical_viewport;
+            }
+
             if changed_window_ids.contains(&window.id())
                 || added_cameras.contains(&entity)
                 || camera_projection.is_changed()
+                || viewport_changed
             {
-                camera_projection.update(window.width(), window.height());
+                let (width, height) = logical_target_size(window, physical_viewport);
+                camera_projection.update(width, height);
                 camera.projection_matrix = camera_projection.get_projection_matrix();
                 camera.depth_calculation = camera_projection.depth_calculation();
             }
         }
     }
 }
+
+/// The logical size rendered into by a camera: its viewport if it has one, otherwise the window.
+fn logical_target_size(window: &Window, physical_viewport: Option<PhysicalViewport>) -> (f32, f32) {
+    match physical_viewport {
+        Some(viewport) => {
+            let scale_factor = window.scale_factor() as f32;
+            (
+                viewport.size.x as f32 / scale_factor,
+                viewport.size.y as f32 / scale_factor,
+            )
+        }
+        None => (window.width(), window.height()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::camera::OrthographicProjection;
+    use raw_window_handle::{RawWindowHandle, WebHandle};
+
+    #[test]
+    fn relative_viewport_scales_with_window() {
+        // right half of the window
+        let viewport = Viewport::relative(Vec2::new(0.5, 0.0), Vec2::new(0.5, 1.0));
+
+        assert_eq!(
+            viewport.physical_rect(800, 600, 1.0),
+            PhysicalViewport {
+                origin: UVec2::new(400, 0),
+                size: UVec2::new(400, 600),
+            }
+        );
+        // the window is resized
+        assert_eq!(
+            viewport.physical_rect(1600, 900, 1.0),
+            PhysicalViewport {
+                origin: UVec2::new(800, 0),
+                size: UVec2::new(800, 900),
+            }
+        );
+        // the scale factor does not affect relative viewports
+        assert_eq!(
+            viewport.physical_rect(1600, 900, 2.0),
+            viewport.physical_rect(1600, 900, 1.0)
+        );
+    }
+
+    #[test]
+    fn absolute_viewport_uses_scale_factor_and_clamps() {
+        let viewport = Viewport::absolute(Vec2::new(100.0, 50.0), Vec2::new(300.0, 200.0));
+
+        assert_eq!(
+            viewport.physical_rect(800, 600, 2.0),
+            PhysicalViewport {
+                origin: UVec2::new(200, 100),
+                size: UVec2::new(600, 400),
+            }
+        );
+        assert_eq!(
+            viewport.physical_rect(400, 300, 2.0),
+            PhysicalViewport {
+                origin: UVec2::new(200, 100),
+                size: UVec2::new(200, 200),
+            }
+        );
+    }
+
+    #[test]
+    fn viewport_outside_of_window_is_empty() {
+        let viewport = Viewport::absolute(Vec2::new(500.0, 0.0), Vec2::new(200.0, 200.0));
+        assert_eq!(
+            viewport.physical_rect(800, 600, 1.0),
+            PhysicalViewport {
+                origin: UVec2::new(500, 0),
+                size: UVec2::new(200, 200),
+            }
+        );
+        // the window shrinks past the viewport's offset
+        assert_eq!(
+            viewport.physical_rect(400, 300, 1.0),
+            PhysicalViewport {
+                origin: UVec2::new(400, 0),
+                size: UVec2::new(0, 200),
+            }
+        );
+        // too small to cover a single pixel
+        let viewport = Viewport::relative(Vec2::ZERO, Vec2::new(0.001, 1.0));
+        assert_eq!(
+            viewport.physical_rect(400, 300, 1.0).size,
+            UVec2::new(0, 300)
+        );
+    }
+
+    #[test]
+    fn world_to_screen_maps_into_viewport() {
+        let mut windows = Windows::default();
+        windows.add(Window::new(
+            WindowId::primary(),
+            &bevy_window::WindowDescriptor::default(),
+            800,
+            600,
+            2.0,
+            None,
+            RawWindowHandle::Web(WebHandle::empty()),
+        ));
+        // top-right quarter of the window
+        let camera = Camera {
+            projection_matrix: OrthographicProjection::default().get_projection_matrix(),
+            window: WindowId::primary(),
+            viewport: Some(Viewport::relative(Vec2::new(0.5, 0.0), Vec2::new(0.5, 0.5))),
+            ..Default::default()
+        };
+        let transform = GlobalTransform::identity();
+
+        // the window is 400x300 logical pixels, and screen space starts at the bottom left
+        assert_eq!(
+            camera.world_to_screen(&windows, &transform, Vec3::new(0.0, 0.0, -1.0)),
+            Some(Vec2::new(300.0, 225.0))
+        );
+        assert_eq!(
+            camera.world_to_screen(&windows, &transform, Vec3::new(-1.0, -1.0, -1.0)),
+            Some(Vec2::new(200.0, 150.0))
+        );
+    }
+}
@@ -5,6 +5,7 @@ mod camera;
 mod projection;
 
 pub use active_cameras::*;
+use bevy_math::UVec4;
 use bevy_transform::components::GlobalTransform;
 use bevy_utils::HashMap;
 use bevy_window::{WindowId, Windows};
@@ -68,6 +69,7 @@ pub struct ExtractedCameraNames {
 pub struct ExtractedCamera {
     pub window_id: WindowId,
     pub name: Option<String>,
+    pub physical_viewport: Option<PhysicalViewport>,
 }
 
 fn extract_cameras(
@@ -82,18 +84,34 @@ fn extract_cameras(
         if let Some((entity, camera, transform, visible_entities)) =
             camera.entity.and_then(|e| query.get(e).ok())
         {
-            if let Some(window) = windows.get(camera.window) {
+            if let Some(window) = windows
+                .get(camera.window)
+                .filter(|window| !window.is_minimized())
+            {
+                // the render pass and the view uniform both read this, so they never disagree
+                let viewport = camera.physical_viewport_rect(window);
+                // wgpu rejects empty viewports, and there would be nothing to draw into anyway
+                if viewport.size.x == 0 || viewport.size.y == 0 {
+                    continue;
+                }
                 entities.insert(name.clone(), entity);
                 commands.get_or_spawn(entity).insert_bundle((
                     ExtractedCamera {
                         window_id: camera.window,
                         name: camera.name.clone(),
+                        physical_viewport: camera.viewport.map(|_| viewport),
                     },
                     ExtractedView {
                         projection: camera.projection_matrix,
                         transform: *transform,
                         width: window.physical_width().max(1),
                         height: window.physical_height().max(1),
+                        viewport: UVec4::new(
+                            viewport.origin.x,
+                            viewport.origin.y,
+                            viewport.size.x,
+                            viewport.size.y,
+                        ),
                         near: camera.near,
                         far: camera.far,
                     },
@@ -35,7 +35,7 @@ use crate::{
     mesh::MeshPlugin,
     primitives::{CubemapFrusta, Frustum},
     render_graph::RenderGraph,
-    render_resource::{RenderPipelineCache, Shader, ShaderLoader},
+    render_resource::{GlobalShaderDefs, RenderPipelineCache, Shader, ShaderLoader},
     renderer::render_system,
     texture::ImagePlugin,
     view::{ViewPlugin, WindowRenderPlugin},
@@ -140,6 +140,7 @@ impl Plugin for RenderPlugin {
             .add_asset::<Shader>()
             .init_asset_loader::<ShaderLoader>()
             .init_resource::<ScratchRenderWorld>()
+            .init_resource::<GlobalShaderDefs>()
             .register_type::<Color>()
             .register_type::<Frustum>()
             .register_type::<CubemapFrusta>();
@@ -147,8 +148,9 @@ impl Plugin for RenderPlugin {
         let asset_server = app.world.get_resource::<AssetServer>().unwrap().clone();
 
         let mut render_app = App::empty();
-        let mut extract_stage =
-            SystemStage::parallel().with_system(RenderPipelineCache::extract_shaders);
+        let mut extract_stage = SystemStage::parallel()
+            .with_system(RenderPipelineCache::extract_shaders)
+            .with_system(RenderPipelineCache::extract_global_shader_defs);
         // don't apply buffers when the stage finishes running
         // extract stage runs on the app world, but the buffers are applied to the render world
         extract_stage.set_apply_buffers(false);
@@ -1,4 +1,5 @@
 use crate::{
+    camera::PhysicalViewport,
     prelude::Color,
     render_resource::{
         BindGroup, BindGroupId, BufferId, BufferSlice, RenderPipeline, RenderPipelineId,
@@ -274,6 +275,25 @@ impl<'a> TrackedRenderPass<'a> {
             .set_viewport(x, y, width, height, min_depth, max_depth)
     }
 
+    /// Restricts rendering to a camera's [`PhysicalViewport`], setting both the viewport and the
+    /// scissor rect.
+    pub fn set_camera_viewport(&mut self, viewport: &PhysicalViewport) {
+        self.set_viewport(
+            viewport.origin.x as f32,
+            viewport.origin.y as f32,
+            viewport.size.x as f32,
+            viewport.size.y as f32,
+            0.0,
+            1.0,
+        );
+        self.set_scissor_rect(
+            viewport.origin.x,
+            viewport.origin.y,
+            viewport.size.x,
+            viewport.size.y,
+        );
+    }
+
     /// Insert a single debug marker.
     ///
     /// This is a GPU debugging feature. This has no effect on the rendering itself.
@@ -1,6 +1,7 @@
 use crate::{
     render_resource::{
-        AsModuleDescriptorError, BindGroupLayout, BindGroupLayoutId, ProcessShaderError,
+        AsModuleDescriptorError, BindGroupLayout, BindGroupLayoutId, ComputePipeline,
+        ComputePipelineDescriptor, ProcessShaderError, RawComputePipelineDescriptor,
         RawFragmentState, RawRenderPipelineDescriptor, RawVertexState, RenderPipeline,
         RenderPipelineDescriptor, Shader, ShaderImport, ShaderProcessor, ShaderReflectError,
     },
@@ -10,7 +11,7 @@ use crate::{
 use bevy_app::EventReader;
 use bevy_asset::{AssetEvent, Assets, Handle};
 use bevy_ecs::system::{Res, ResMut};
-use bevy_utils::{tracing::error, HashMap, HashSet};
+use bevy_utils::{tracing::error, Duration, HashMap, HashSet, Instant};
 use std::{collections::hash_map::Entry, hash::Hash, ops::Deref, sync::Arc};
 use thiserror::Error;
 use wgpu::{PipelineLayoutDescriptor, ShaderModule, VertexBufferLayout};
@@ -19,7 +20,7 @@ use super::ProcessedShader;
 
 #[derive(Default)]
 pub struct ShaderData {
-    pipelines: HashSet<CachedPipelineId>,
+    pipelines: HashSet<CachedPipelineRef>,
     processed_shaders: HashMap<Vec<String>, Arc<ShaderModule>>,
     resolved_imports: HashMap<ShaderImport, Handle<Shader>>,
     dependents: HashSet<Handle<Shader>>,
@@ -28,6 +29,76 @@ pub struct ShaderData {
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
 pub struct CachedPipelineId(usize);
 
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct CachedComputePipelineId(usize);
+
+/// A render or compute pipeline using a shader, re-queued when the shader changes.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+enum CachedPipelineRef {
+    Render(CachedPipelineId),
+    Compute(CachedComputePipelineId),
+}
+
+/// Shader defs that are applied to the shader stages of every pipeline in the
+/// [`RenderPipelineCache`], in addition to the defs set on each stage.
+///
+/// Insert this resource into the app world to toggle global features such as `DEBUG_NORMALS`.
+/// Changing it re-queues every cached pipeline. Processed shaders are cached per set of shader
+/// defs, so toggling a def back and forth does not reprocess shaders that were already compiled.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GlobalShaderDefs {
+    defs: Vec<String>,
+}
+
+impl GlobalShaderDefs {
+    /// Adds `def` to the global shader defs. Returns `false` if it was already present.
+    pub fn add(&mut self, def: impl Into<String>) -> bool {
+        let def = def.into();
+        if self.contains(&def) {
+            return false;
+        }
+        self.defs.push(def);
+        true
+    }
+
+    /// Removes `def` from the global shader defs. Returns `false` if it was not present.
+    pub fn remove(&mut self, def: &str) -> bool {
+        let len = self.defs.len();
+        self.defs.retain(|d| d != def);
+        self.defs.len() != len
+    }
+
+    #[inline]
+    pub fn contains(&self, def: &str) -> bool {
+        self.defs.iter().any(|d| d == def)
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.defs.clear();
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.defs.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.defs.iter().map(|d| d.as_str())
+    }
+
+    /// Returns `shader_defs` followed by every global def that it does not already contain.
+    pub fn apply(&self, shader_defs: &[String]) -> Vec<String> {
+        let mut defs = shader_defs.to_vec();
+        for def in self.defs.iter() {
+            if !shader_defs.contains(def) {
+                defs.push(def.clone());
+            }
+        }
+        defs
+    }
+}
+
 impl CachedPipelineId {
     pub const INVALID: Self = CachedPipelineId(usize::MAX);
 }
@@ -39,13 +110,15 @@ struct ShaderCache {
     import_path_shaders: HashMap<ShaderImport, Handle<Shader>>,
     waiting_on_import: HashMap<ShaderImport, Vec<Handle<Shader>>>,
     processor: ShaderProcessor,
+    modules_created: usize,
+    module_cache_hits: usize,
 }
 
 impl ShaderCache {
     fn get(
         &mut self,
         render_device: &RenderDevice,
-        pipeline: CachedPipelineId,
+        pipeline: CachedPipelineRef,
         handle: &Handle<Shader>,
         shader_defs: &[String],
     ) -> Result<Arc<ShaderModule>, RenderPipelineError> {
@@ -71,8 +144,12 @@ impl ShaderCache {
 
         // PERF: this shader_defs clone isn't great. use raw_entry_mut when it stabilizes
         let module = match data.processed_shaders.entry(shader_defs.to_vec()) {
-            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Occupied(entry) => {
+                self.module_cache_hits += 1;
+                entry.into_mut()
+            }
             Entry::Vacant(entry) => {
+                self.modules_created += 1;
                 let processed = self.processor.process(
                     shader,
                     shader_defs,
@@ -94,7 +171,7 @@ impl ShaderCache {
         Ok(module.clone())
     }
 
-    fn clear(&mut self, handle: &Handle<Shader>) -> Vec<CachedPipelineId> {
+    fn clear(&mut self, handle: &Handle<Shader>) -> Vec<CachedPipelineRef> {
         let mut shaders_to_clear = vec![handle.clone_weak()];
         let mut pipelines_to_queue = Vec::new();
         while let Some(handle) = shaders_to_clear.pop() {
@@ -108,7 +185,7 @@ impl ShaderCache {
         pipelines_to_queue
     }
 
-    fn set_shader(&mut self, handle: &Handle<Shader>, shader: Shader) -> Vec<CachedPipelineId> {
+    fn set_shader(&mut self, handle: &Handle<Shader>, shader: Shader) -> Vec<CachedPipelineRef> {
         let pipelines_to_queue = self.clear(handle);
         if let Some(path) = shader.import_path() {
             self.import_path_shaders
@@ -145,7 +222,7 @@ impl ShaderCache {
         pipelines_to_queue
     }
 
-    fn remove(&mut self, handle: &Handle<Shader>) -> Vec<CachedPipelineId> {
+    fn remove(&mut self, handle: &Handle<Shader>) -> Vec<CachedPipelineRef> {
         let pipelines_to_queue = self.clear(handle);
         if let Some(shader) = self.shaders.remove(handle) {
             if let Some(import_path) = shader.import_path() {
@@ -188,6 +265,23 @@ pub struct RenderPipelineCache {
     device: RenderDevice,
     pipelines: Vec<CachedPipeline>,
     waiting_pipelines: HashSet<CachedPipelineId>,
+    compute_pipelines: Vec<CachedComputePipeline>,
+    waiting_compute_pipelines: HashSet<CachedComputePipelineId>,
+    global_shader_defs: GlobalShaderDefs,
+    stats: PipelineCacheStats,
+}
+
+/// Counters describing the work done by a [`RenderPipelineCache`], for profiling load times.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PipelineCacheStats {
+    /// The number of shader modules processed and created, one per shader and set of shader defs.
+    pub shader_modules_created: usize,
+    /// The number of times a pipeline stage reused an already created shader module.
+    pub shader_module_cache_hits: usize,
+    pub render_pipelines_created: usize,
+    pub compute_pipelines_created: usize,
+    /// The time spent processing shaders and creating pipelines.
+    pub compile_time: Duration,
 }
 
 struct CachedPipeline {
@@ -195,6 +289,18 @@ struct CachedPipeline {
     state: CachedPipelineState,
 }
 
+struct CachedComputePipeline {
+    descriptor: ComputePipelineDescriptor,
+    state: CachedComputePipelineState,
+}
+
+/// The shader defs used to compile each stage of a cached pipeline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PipelineShaderDefs {
+    pub vertex: Vec<String>,
+    pub fragment: Option<Vec<String>>,
+}
+
 #[derive(Debug)]
 pub enum CachedPipelineState {
     Queued,
@@ -214,6 +320,25 @@ impl CachedPipelineState {
     }
 }
 
+#[derive(Debug)]
+pub enum CachedComputePipelineState {
+    Queued,
+    Ok(ComputePipeline),
+    Err(RenderPipelineError),
+}
+
+impl CachedComputePipelineState {
+    pub fn unwrap(&self) -> &ComputePipeline {
+        match self {
+            CachedComputePipelineState::Ok(pipeline) => pipeline,
+            CachedComputePipelineState::Queued => {
+                panic!("Pipeline has not been compiled yet. It is still in the 'Queued' state.")
+            }
+            CachedComputePipelineState::Err(err) => panic!("{}", err),
+        }
+    }
+}
+
 #[derive(Error, Debug)]
 pub enum RenderPipelineError {
     #[error(
@@ -236,6 +361,33 @@ impl RenderPipelineCache {
             shader_cache: Default::default(),
             waiting_pipelines: Default::default(),
             pipelines: Default::default(),
+            waiting_compute_pipelines: Default::default(),
+            compute_pipelines: Default::default(),
+            global_shader_defs: Default::default(),
+            stats: Default::default(),
+        }
+    }
+
+    #[inline]
+    pub fn global_shader_defs(&self) -> &GlobalShaderDefs {
+        &self.global_shader_defs
+    }
+
+    /// Replaces the [`GlobalShaderDefs`] and re-queues every pipeline if they changed.
+    pub fn set_global_shader_defs(&mut self, global_shader_defs: GlobalShaderDefs) {
+        if self.global_shader_defs == global_shader_defs {
+            return;
+        }
+
+        self.global_shader_defs = global_shader_defs;
+        for (index, cached_pipeline) in self.pipelines.iter_mut().enumerate() {
+            cached_pipeline.state = CachedPipelineState::Queued;
+            self.waiting_pipelines.insert(CachedPipelineId(index));
+        }
+        for (index, cached_pipeline) in self.compute_pipelines.iter_mut().enumerate() {
+            cached_pipeline.state = CachedComputePipelineState::Queued;
+            self.waiting_compute_pipelines
+                .insert(CachedComputePipelineId(index));
         }
     }
 
@@ -253,6 +405,97 @@ impl RenderPipelineCache {
         }
     }
 
+    #[inline]
+    pub fn get_descriptor(&self, id: CachedPipelineId) -> &RenderPipelineDescriptor {
+        &self.pipelines[id.0].descriptor
+    }
+
+    /// Returns the shader defs a pipeline's vertex and fragment shaders are (or will be)
+    /// compiled with, including the [`GlobalShaderDefs`].
+    ///
+    /// Phase items store the [`CachedPipelineId`] they are drawn with, so this answers which
+    /// defines an entity was specialized with.
+    pub fn get_shader_defs(&self, id: CachedPipelineId) -> PipelineShaderDefs {
+        let descriptor = self.get_descriptor(id);
+        PipelineShaderDefs {
+            vertex: self
+                .global_shader_defs
+                .apply(&descriptor.vertex.shader_defs),
+            fragment: descriptor
+                .fragment
+                .as_ref()
+                .map(|fragment| self.global_shader_defs.apply(&fragment.shader_defs)),
+        }
+    }
+
+    #[inline]
+    pub fn get_compute_state(&self, id: CachedComputePipelineId) -> &CachedComputePipelineState {
+        &self.compute_pipelines[id.0].state
+    }
+
+    #[inline]
+    pub fn get_compute(&self, id: CachedComputePipelineId) -> Option<&ComputePipeline> {
+        if let CachedComputePipelineState::Ok(pipeline) = &self.compute_pipelines[id.0].state {
+            Some(pipeline)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub fn get_compute_descriptor(
+        &self,
+        id: CachedComputePipelineId,
+    ) -> &ComputePipelineDescriptor {
+        &self.compute_pipelines[id.0].descriptor
+    }
+
+    /// The number of render and compute pipelines that are queued or waiting on shaders that are
+    /// not loaded yet.
+    #[inline]
+    pub fn pending_count(&self) -> usize {
+        self.waiting_pipelines.len() + self.waiting_compute_pipelines.len()
+    }
+
+    /// Returns the counters accumulated since the cache was created or
+    /// [`RenderPipelineCache::reset_stats`] was last called.
+    pub fn stats(&self) -> PipelineCacheStats {
+        PipelineCacheStats {
+            shader_modules_created: self.shader_cache.modules_created,
+            shader_module_cache_hits: self.shader_cache.module_cache_hits,
+            ..self.stats
+        }
+    }
+
+    /// Resets all [`PipelineCacheStats`] counters to zero, for example at the start of a level load.
+    pub fn reset_stats(&mut self) {
+        self.stats = PipelineCacheStats::default();
+        self.shader_cache.modules_created = 0;
+        self.shader_cache.module_cache_hits = 0;
+    }
+
+    /// Returns `true` if every pipeline in `ids` has finished compiling successfully.
+    ///
+    /// Pipelines are compiled during [`RenderStage::Render`](crate::RenderStage::Render), and may
+    /// stay pending across several frames while their shaders (and shader imports) load. Use
+    /// this to gate things like revealing a level until the pipelines it needs are ready.
+    pub fn is_ready(&self, ids: &[CachedPipelineId]) -> bool {
+        ids.iter()
+            .all(|id| matches!(self.get_state(*id), CachedPipelineState::Ok(_)))
+    }
+
+    /// Returns `true` if every compute pipeline in `ids` has finished compiling successfully.
+    ///
+    /// This is the compute counterpart of [`RenderPipelineCache::is_ready`].
+    pub fn is_compute_ready(&self, ids: &[CachedComputePipelineId]) -> bool {
+        ids.iter().all(|id| {
+            matches!(
+                self.get_compute_state(*id),
+                CachedComputePipelineState::Ok(_)
+            )
+        })
+    }
+
     pub fn queue(&mut self, descriptor: RenderPipelineDescriptor) -> CachedPipelineId {
         let id = CachedPipelineId(self.pipelines.len());
         self.pipelines.push(CachedPipeline {
@@ -263,23 +506,61 @@ impl RenderPipelineCache {
         id
     }
 
+    /// Queues a compute pipeline for compilation. Like render pipelines, it is compiled during
+    /// [`RenderStage::Render`](crate::RenderStage::Render) once its shader is loaded, and
+    /// recompiled when its shader changes.
+    pub fn queue_compute(
+        &mut self,
+        descriptor: ComputePipelineDescriptor,
+    ) -> CachedComputePipelineId {
+        let id = CachedComputePipelineId(self.compute_pipelines.len());
+        self.compute_pipelines.push(CachedComputePipeline {
+            descriptor,
+            state: CachedComputePipelineState::Queued,
+        });
+        self.waiting_compute_pipelines.insert(id);
+        id
+    }
+
+    fn requeue(&mut self, pipeline: CachedPipelineRef) {
+        match pipeline {
+            CachedPipelineRef::Render(id) => {
+                self.pipelines[id.0].state = CachedPipelineState::Queued;
+                self.waiting_pipelines.insert(id);
+            }
+            CachedPipelineRef::Compute(id) => {
+                self.compute_pipelines[id.0].state = CachedComputePipelineState::Queued;
+                self.waiting_compute_pipelines.insert(id);
+            }
+        }
+    }
+
     fn set_shader(&mut self, handle: &Handle<Shader>, shader: &Shader) {
         let pipelines_to_queue = self.shader_cache.set_shader(handle, shader.clone());
         for cached_pipeline in pipelines_to_queue {
-            self.pipelines[cached_pipeline.0].state = CachedPipelineState::Queued;
-            self.waiting_pipelines.insert(cached_pipeline);
+            self.requeue(cached_pipeline);
         }
     }
 
     fn remove_shader(&mut self, shader: &Handle<Shader>) {
         let pipelines_to_queue = self.shader_cache.remove(shader);
         for cached_pipeline in pipelines_to_queue {
-            self.pipelines[cached_pipeline.0].state = CachedPipelineState::Queued;
-            self.waiting_pipelines.insert(cached_pipeline);
+            self.requeue(cached_pipeline);
         }
     }
 
     pub fn process_queue(&mut self) {
+        if self.waiting_pipelines.is_empty() && self.waiting_compute_pipelines.is_empty() {
+            return;
+        }
+
+        let start = Instant::now();
+        self.process_render_queue();
+        self.process_compute_queue();
+        self.stats.compile_time += start.elapsed();
+    }
+
+    fn process_render_queue(&mut self) {
         let pipelines = std::mem::take(&mut self.waiting_pipelines);
         for id in pipelines {
             let state = &mut self.pipelines[id.0];
@@ -287,18 +568,8 @@ impl RenderPipelineCache {
                 CachedPipelineState::Ok(_) => continue,
                 CachedPipelineState::Queued => {}
                 CachedPipelineState::Err(err) => {
-                    match err {
-                        RenderPipelineError::ShaderNotLoaded(_)
-                        | RenderPipelineError::ShaderImportNotYetAvailable => { /* retry */ }
-                        // shader could not be processed ... retrying won't help
-                        RenderPipelineError::ProcessShaderError(err) => {
-                            error!("failed to process shader: {}", err);
-                            continue;
-                        }
-                        RenderPipelineError::AsModuleDescriptorError(err, source) => {
-                            log_shader_error(source, err);
-                            continue;
-                        }
+                    if !should_retry(err) {
+                        continue;
                     }
                 }
             }
@@ -306,9 +577,11 @@ impl RenderPipelineCache {
             let descriptor = &state.descriptor;
             let vertex_module = match self.shader_cache.get(
                 &self.device,
-                id,
+                CachedPipelineRef::Render(id),
                 &descriptor.vertex.shader,
-                &descriptor.vertex.shader_defs,
+                &self
+                    .global_shader_defs
+                    .apply(&descriptor.vertex.shader_defs),
             ) {
                 Ok(module) => module,
                 Err(err) => {
@@ -321,9 +594,9 @@ impl RenderPipelineCache {
             let fragment_data = if let Some(fragment) = &descriptor.fragment {
                 let fragment_module = match self.shader_cache.get(
                     &self.device,
-                    id,
+                    CachedPipelineRef::Render(id),
                     &fragment.shader,
-                    &fragment.shader_defs,
+                    &self.global_shader_defs.apply(&fragment.shader_defs),
                 ) {
                     Ok(module) => module,
                     Err(err) => {
@@ -381,6 +654,55 @@ impl RenderPipelineCache {
 
             let pipeline = self.device.create_render_pipeline(&descriptor);
             state.state = CachedPipelineState::Ok(pipeline);
+            self.stats.render_pipelines_created += 1;
+        }
+    }
+
+    fn process_compute_queue(&mut self) {
+        let pipelines = std::mem::take(&mut self.waiting_compute_pipelines);
+        for id in pipelines {
+            let state = &mut self.compute_pipelines[id.0];
+            match &state.state {
+                CachedComputePipelineState::Ok(_) => continue,
+                CachedComputePipelineState::Queued => {}
+                CachedComputePipelineState::Err(err) => {
+                    if !should_retry(err) {
+                        continue;
+                    }
+                }
+            }
+
+            let descriptor = &state.descriptor;
+            let module = match self.shader_cache.get(
+                &self.device,
+                CachedPipelineRef::Compute(id),
+                &descriptor.shader,
+                &self.global_shader_defs.apply(&descriptor.shader_defs),
+            ) {
+                Ok(module) => module,
+                Err(err) => {
+                    state.state = CachedComputePipelineState::Err(err);
+                    self.waiting_compute_pipelines.insert(id);
+                    continue;
+                }
+            };
+
+            let layout = if let Some(layout) = &descriptor.layout {
+                Some(self.layout_cache.get(&self.device, layout))
+            } else {
+                None
+            };
+
+            let descriptor = RawComputePipelineDescriptor {
+                label: descriptor.label.as_deref(),
+                layout,
+                module: &module,
+                entry_point: descriptor.entry_point.deref(),
+            };
+
+            let pipeline = self.device.create_compute_pipeline(&descriptor);
+            state.state = CachedComputePipelineState::Ok(pipeline);
+            self.stats.compute_pipelines_created += 1;
         }
     }
 
@@ -388,6 +710,16 @@ impl RenderPipelineCache {
         cache.process_queue();
     }
 
+    pub(crate) fn extract_global_shader_defs(
+        mut world: ResMut<RenderWorld>,
+        global_shader_defs: Res<GlobalShaderDefs>,
+    ) {
+        if global_shader_defs.is_changed() {
+            let mut cache = world.get_resource_mut::<Self>().unwrap();
+            cache.set_global_shader_defs(global_shader_defs.clone());
+        }
+    }
+
     pub(crate) fn extract_shaders(
         mut world: ResMut<RenderWorld>,
         shaders: Res<Assets<Shader>>,
@@ -407,6 +739,24 @@ impl RenderPipelineCache {
     }
 }
 
+/// Returns `true` if a pipeline that failed with `err` may compile later, logging the error
+/// otherwise.
+fn should_retry(err: &RenderPipelineError) -> bool {
+    match err {
+        RenderPipelineError::ShaderNotLoaded(_)
+        | RenderPipelineError::ShaderImportNotYetAvailable => true,
+        // shader could not be processed ... retrying won't help
+        RenderPipelineError::ProcessShaderError(err) => {
+            error!("failed to process shader: {}", err);
+            false
+        }
+        RenderPipelineError::AsModuleDescriptorError(err, source) => {
+            log_shader_error(source, err);
+            false
+        }
+    }
+}
+
 fn log_shader_error(source: &ProcessedShader, error: &AsModuleDescriptorError) {
     use codespan_reporting::{
         diagnostic::{Diagnostic, Label},
@@ -490,3 +840,34 @@ fn log_shader_error(source: &ProcessedShader, error: &AsModuleDescriptorError) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::GlobalShaderDefs;
+
+    #[test]
+    fn global_shader_defs_apply() {
+        let mut global_shader_defs = GlobalShaderDefs::default();
+        assert_eq!(
+            global_shader_defs.apply(&["TEXTURE".to_string()]),
+            vec!["TEXTURE".to_string()]
+        );
+
+        assert!(global_shader_defs.add("DEBUG_NORMALS"));
+        assert!(!global_shader_defs.add("DEBUG_NORMALS"));
+        for shader_defs in [vec![], vec!["TEXTURE".to_string()]] {
+            let applied = global_shader_defs.apply(&shader_defs);
+            assert!(applied.iter().any(|def| def == "DEBUG_NORMALS"));
+            assert!(shader_defs.iter().all(|def| applied.contains(def)));
+        }
+
+        // defs that are already set on the stage are not duplicated
+        assert_eq!(
+            global_shader_defs.apply(&["DEBUG_NORMALS".to_string()]),
+            vec!["DEBUG_NORMALS".to_string()]
+        );
+
+        global_shader_defs.clear();
+        assert!(global_shader_defs.apply(&[]).is_empty());
+    }
+}
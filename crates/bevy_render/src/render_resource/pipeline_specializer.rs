@@ -15,6 +15,15 @@ impl<S: SpecializedPipeline> Default for SpecializedPipelines<S> {
 }
 
 impl<S: SpecializedPipeline> SpecializedPipelines<S> {
+    /// Returns the id of the pipeline previously queued for `key`, without queueing it.
+    ///
+    /// Check [`RenderPipelineCache::get_state`] with this id to find out whether the pipeline has
+    /// been compiled yet.
+    #[inline]
+    pub fn get(&self, key: &S::Key) -> Option<CachedPipelineId> {
+        self.cache.get(key).copied()
+    }
+
     pub fn specialize(
         &mut self,
         cache: &mut RenderPipelineCache,
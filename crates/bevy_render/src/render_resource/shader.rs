@@ -2,7 +2,7 @@ use bevy_asset::{AssetLoader, Handle, LoadContext, LoadedAsset};
 use bevy_reflect::{TypeUuid, Uuid};
 use bevy_utils::{tracing::error, BoxedFuture, HashMap};
 use naga::back::wgsl::WriterFlags;
-use naga::{valid::ModuleInfo, Module};
+use naga::{valid::ModuleInfo, ConstantInner, Module, ScalarValue};
 use once_cell::sync::Lazy;
 use regex::Regex;
 use std::{
@@ -209,6 +209,27 @@ impl ShaderReflection {
     pub fn get_wgsl(&self) -> Result<String, naga::back::wgsl::Error> {
         naga::back::wgsl::write_string(&self.module, &self.module_info, WriterFlags::EXPLICIT_TYPES)
     }
+
+    /// Iterates over the named scalar constants declared by the shader, such as a module-scope
+    /// `let MAX_LIGHTS: u32 = 16u;` in WGSL, a `const` in GLSL or a named SPIR-V constant.
+    ///
+    /// This lets the engine read back compile-time values (for example to size buffers) and
+    /// detect mismatches with the shader.
+    pub fn constants(&self) -> impl Iterator<Item = (&str, ScalarValue)> {
+        self.module.constants.iter().filter_map(|(_, constant)| {
+            match (&constant.name, &constant.inner) {
+                (Some(name), ConstantInner::Scalar { value, .. }) => Some((name.as_str(), *value)),
+                _ => None,
+            }
+        })
+    }
+
+    /// Returns the value of the named scalar constant declared by the shader, if any.
+    pub fn get_constant(&self, name: &str) -> Option<ScalarValue> {
+        self.constants()
+            .find(|(constant_name, _)| *constant_name == name)
+            .map(|(_, value)| value)
+    }
 }
 
 #[derive(Default)]
@@ -479,9 +500,11 @@ mod tests {
     use bevy_asset::{Handle, HandleUntyped};
     use bevy_reflect::TypeUuid;
     use bevy_utils::HashMap;
-    use naga::ShaderStage;
+    use naga::{ScalarValue, ShaderStage};
 
-    use crate::render_resource::{ProcessShaderError, Shader, ShaderImport, ShaderProcessor};
+    use crate::render_resource::{
+        ProcessShaderError, ProcessedShader, Shader, ShaderImport, ShaderProcessor,
+    };
     #[rustfmt::skip]
 const WGSL: &str = r"
 struct View {
@@ -1205,4 +1228,36 @@ fn in_main() { }
             .unwrap();
         assert_eq!(result.get_wgsl_source().unwrap(), EXPECTED);
     }
+
+    #[test]
+    fn reflect_named_constants() {
+        const WGSL_CONSTANTS: &str = r"
+let MAX_LIGHTS: u32 = 16u;
+let TILE_SIZE: f32 = 8.0;
+
+struct Lights {
+    count: u32;
+};
+[[group(0), binding(0)]]
+var<uniform> lights: Lights;
+
+[[stage(fragment)]]
+fn fragment() -> [[location(0)]] vec4<f32> {
+    return vec4<f32>(f32(min(lights.count, MAX_LIGHTS)) / TILE_SIZE);
+}
+";
+        let reflection = ProcessedShader::Wgsl(WGSL_CONSTANTS.into())
+            .reflect()
+            .unwrap();
+
+        assert!(matches!(
+            reflection.get_constant("MAX_LIGHTS"),
+            Some(ScalarValue::Uint(16))
+        ));
+        assert!(matches!(
+            reflection.get_constant("TILE_SIZE"),
+            Some(ScalarValue::Float(size)) if (size - 8.0).abs() < f64::EPSILON
+        ));
+        assert!(reflection.get_constant("MAX_SHADOWS").is_none());
+    }
 }
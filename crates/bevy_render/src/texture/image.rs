@@ -8,6 +8,7 @@ use crate::{
 use bevy_asset::HandleUntyped;
 use bevy_ecs::system::{lifetimeless::SRes, SystemParamItem};
 use bevy_reflect::TypeUuid;
+use std::num::NonZeroU8;
 use thiserror::Error;
 use wgpu::{
     Extent3d, ImageCopyTexture, ImageDataLayout, Origin3d, TextureDimension, TextureFormat,
@@ -163,6 +164,15 @@ impl Image {
         });
     }
 
+    /// Enables anisotropic filtering with up to `anisotropy` samples.
+    ///
+    /// The value is rounded down to the nearest value supported by the GPU backends (1, 2, 4, 8
+    /// or 16), and `0` or `1` disables anisotropic filtering. On devices without anisotropic
+    /// filtering support the sampler falls back to regular filtering.
+    pub fn set_anisotropy(&mut self, anisotropy: u8) {
+        self.sampler_descriptor.anisotropy_clamp = valid_anisotropy_clamp(anisotropy);
+    }
+
     /// Convert a texture from a format to another
     /// Only a few formats are supported as input and output:
     /// - `TextureFormat::R8Unorm`
@@ -397,7 +407,14 @@ impl RenderAsset for Image {
         (render_device, render_queue): &mut SystemParamItem<Self::Param>,
     ) -> Result<Self::PreparedAsset, PrepareAssetError<Self::ExtractedAsset>> {
         let texture = render_device.create_texture(&image.texture_descriptor);
-        let sampler = render_device.create_sampler(&image.sampler_descriptor);
+        // an invalid clamp would fail sampler creation, so round it down to a valid one instead
+        let sampler = render_device.create_sampler(&wgpu::SamplerDescriptor {
+            anisotropy_clamp: image
+                .sampler_descriptor
+                .anisotropy_clamp
+                .and_then(|clamp| valid_anisotropy_clamp(clamp.get())),
+            ..image.sampler_descriptor.clone()
+        });
 
         let format_size = image.texture_descriptor.format.pixel_size();
         render_queue.write_texture(
@@ -433,3 +450,44 @@ impl RenderAsset for Image {
         })
     }
 }
+
+/// The largest anisotropy clamp accepted by the GPU backends.
+///
+/// wgpu does not expose a per-device anisotropy limit. It rejects samplers whose clamp is larger
+/// than this or not a power of two, and creates them without anisotropy on devices lacking
+/// `DownlevelFlags::ANISOTROPIC_FILTERING`, so no device-specific clamp is needed.
+const MAX_ANISOTROPY: u8 = 16;
+
+/// Rounds `anisotropy` down to a power of two no larger than [`MAX_ANISOTROPY`], or `None` if it
+/// does not enable anisotropic filtering.
+fn valid_anisotropy_clamp(anisotropy: u8) -> Option<NonZeroU8> {
+    let anisotropy = anisotropy.min(MAX_ANISOTROPY);
+    if anisotropy <= 1 {
+        return None;
+    }
+    NonZeroU8::new(1 << (7 - anisotropy.leading_zeros()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn anisotropy_is_clamped_to_supported_values() {
+        let mut image = Image::default();
+
+        image.set_anisotropy(8);
+        assert_eq!(image.sampler_descriptor.anisotropy_clamp, NonZeroU8::new(8));
+        image.set_anisotropy(12);
+        assert_eq!(image.sampler_descriptor.anisotropy_clamp, NonZeroU8::new(8));
+        image.set_anisotropy(64);
+        assert_eq!(
+            image.sampler_descriptor.anisotropy_clamp,
+            NonZeroU8::new(16)
+        );
+        image.set_anisotropy(1);
+        assert_eq!(image.sampler_descriptor.anisotropy_clamp, None);
+        image.set_anisotropy(0);
+        assert_eq!(image.sampler_descriptor.anisotropy_clamp, None);
+    }
+}
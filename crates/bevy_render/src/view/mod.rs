@@ -17,7 +17,7 @@ use crate::{
 };
 use bevy_app::{App, Plugin};
 use bevy_ecs::prelude::*;
-use bevy_math::{Mat4, Vec3};
+use bevy_math::{Mat4, UVec4, Vec3, Vec4};
 use bevy_transform::components::GlobalTransform;
 
 pub struct ViewPlugin;
@@ -62,8 +62,13 @@ pub fn extract_msaa(mut commands: Commands, msaa: Res<Msaa>) {
 pub struct ExtractedView {
     pub projection: Mat4,
     pub transform: GlobalTransform,
+    /// The width of the render target in physical pixels.
     pub width: u32,
+    /// The height of the render target in physical pixels.
     pub height: u32,
+    /// The physical pixel rect of the render target this view draws to, as the origin (`x`, `y`)
+    /// and size (`z`, `w`).
+    pub viewport: UVec4,
     pub near: f32,
     pub far: f32,
 }
@@ -78,6 +83,7 @@ pub struct ViewUniform {
     far: f32,
     width: f32,
     height: f32,
+    viewport: Vec4,
 }
 
 #[derive(Default)]
@@ -141,6 +147,7 @@ fn prepare_view_uniforms(
                 far: camera.far,
                 width: camera.width as f32,
                 height: camera.height as f32,
+                viewport: camera.viewport.as_vec4(),
             }),
         };
 
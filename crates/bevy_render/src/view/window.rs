@@ -67,7 +67,13 @@ impl DerefMut for ExtractedWindows {
 
 fn extract_windows(mut render_world: ResMut<RenderWorld>, windows: Res<Windows>) {
     let mut extracted_windows = render_world.get_resource_mut::<ExtractedWindows>().unwrap();
-    for window in windows.iter() {
+    // minimized windows have no area to render to. their surfaces are recreated on restore
+    extracted_windows.retain(|id, _| {
+        windows
+            .get(*id)
+            .map_or(false, |window| !window.is_minimized())
+    });
+    for window in windows.iter().filter(|window| !window.is_minimized()) {
         let (new_width, new_height) = (
             window.physical_width().max(1),
             window.physical_height().max(1),
@@ -122,6 +128,13 @@ pub fn prepare_windows(
     render_instance: Res<RenderInstance>,
 ) {
     let window_surfaces = window_surfaces.deref_mut();
+    // drop the surfaces of closed and minimized windows
+    window_surfaces
+        .surfaces
+        .retain(|id, _| windows.contains_key(id));
+    window_surfaces
+        .configured_windows
+        .retain(|id| windows.contains_key(id));
     for window in windows.windows.values_mut() {
         let surface = window_surfaces
             .surfaces
@@ -0,0 +1,222 @@
+use crate::{PositionType, Style, Val};
+use bevy_ecs::{
+    prelude::Component,
+    reflect::ReflectComponent,
+    system::{Local, Query, ResMut},
+};
+use bevy_math::Vec2;
+use bevy_reflect::Reflect;
+use bevy_render::view::Visibility;
+use bevy_utils::{HashMap, HashSet};
+use bevy_window::{WindowId, Windows};
+
+/// Turns a UI node, typically an [`ImageBundle`](crate::entity::ImageBundle), into a software
+/// cursor for platforms or window modes where the OS cursor cannot be styled or hidden reliably.
+///
+/// When the first software cursor of a window is spawned the OS cursor of that window is hidden,
+/// and it is shown again once the last one is despawned. The node follows the latest cursor
+/// position, and is hidden while the cursor is outside the window. Positions are in logical
+/// pixels, so the image is sized consistently across scale factors. Spawn it after the rest of the
+/// UI so it is drawn on top.
+#[derive(Component, Debug, Default, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct SoftwareCursor {
+    /// Offset from the top-left corner of the node to the point that tracks the cursor.
+    pub hotspot: Vec2,
+    /// The window whose cursor is followed. Defaults to the primary window.
+    #[reflect(ignore)]
+    pub window: WindowId,
+}
+
+pub fn software_cursor_system(
+    // the visibility of each window's OS cursor before its first software cursor was spawned
+    mut os_cursor_was_visible: Local<HashMap<WindowId, bool>>,
+    mut windows: ResMut<Windows>,
+    mut cursor_query: Query<(&SoftwareCursor, &mut Style, &mut Visibility)>,
+) {
+    let cursor_windows: HashSet<WindowId> = cursor_query
+        .iter_mut()
+        .map(|(cursor, _, _)| cursor.window)
+        .collect();
+
+    os_cursor_was_visible.retain(|id, was_visible| {
+        if cursor_windows.contains(id) {
+            return true;
+        }
+        if let Some(window) = windows.get_mut(*id) {
+            if *was_visible && !window.cursor_visible() {
+                window.set_cursor_visibility(true);
+            }
+        }
+        false
+    });
+    // only hide the cursor once, so users can still show it
+    for id in cursor_windows {
+        if let Some(window) = windows.get_mut(id) {
+            os_cursor_was_visible.entry(id).or_insert_with(|| {
+                let visible = window.cursor_visible();
+                if visible {
+                    window.set_cursor_visibility(false);
+                }
+                visible
+            });
+        }
+    }
+
+    for (cursor, mut style, mut visibility) in cursor_query.iter_mut() {
+        // cursor positions have a bottom-left origin, ui positions a top-left one
+        let position = windows.get(cursor.window).and_then(|window| {
+            window
+                .cursor_position()
+                .map(|position| Vec2::new(position.x, window.height() - position.y))
+        });
+        let position = if let Some(position) = position {
+            position - cursor.hotspot
+        } else {
+            if visibility.is_visible {
+                visibility.is_visible = false;
+            }
+            continue;
+        };
+
+        if !visibility.is_visible {
+            visibility.is_visible = true;
+        }
+        // only write on change, to avoid recomputing the layout every frame
+        if style.position_type != PositionType::Absolute
+            || style.position.left != Val::Px(position.x)
+            || style.position.top != Val::Px(position.y)
+        {
+            style.position_type = PositionType::Absolute;
+            style.position.left = Val::Px(position.x);
+            style.position.top = Val::Px(position.y);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::{
+        schedule::{Schedule, Stage, SystemStage},
+        world::World,
+    };
+    use bevy_math::DVec2;
+    use bevy_window::{Window, WindowDescriptor};
+    use raw_window_handle::{RawWindowHandle, WebHandle};
+
+    #[test]
+    fn software_cursor_follows_cursor() {
+        let mut world = World::new();
+        let mut window = Window::new(
+            WindowId::primary(),
+            &WindowDescriptor::default(),
+            800,
+            600,
+            2.0,
+            None,
+            RawWindowHandle::Web(WebHandle::empty()),
+        );
+        window.update_cursor_physical_position_from_backend(Some(DVec2::new(200.0, 100.0)));
+        let mut windows = Windows::default();
+        windows.add(window);
+        world.insert_resource(windows);
+
+        let cursor = world
+            .spawn()
+            .insert_bundle((
+                SoftwareCursor {
+                    hotspot: Vec2::new(2.0, 4.0),
+                    ..Default::default()
+                },
+                Style::default(),
+                Visibility { is_visible: false },
+            ))
+            .id();
+
+        let mut schedule = Schedule::default();
+        let mut update_stage = SystemStage::parallel();
+        update_stage.add_system(software_cursor_system);
+        schedule.add_stage("update", update_stage);
+        schedule.run(&mut world);
+
+        // the window is 400x300 logical pixels, and the cursor at (100, 50) from the bottom left
+        let style = world.get::<Style>(cursor).unwrap();
+        assert_eq!(style.position_type, PositionType::Absolute);
+        assert_eq!(style.position.left, Val::Px(98.0));
+        assert_eq!(style.position.top, Val::Px(246.0));
+        assert!(world.get::<Visibility>(cursor).unwrap().is_visible);
+        let windows = world.get_resource::<Windows>().unwrap();
+        assert!(!windows.get_primary().unwrap().cursor_visible());
+
+        world
+            .get_resource_mut::<Windows>()
+            .unwrap()
+            .get_primary_mut()
+            .unwrap()
+            .update_cursor_physical_position_from_backend(None);
+        schedule.run(&mut world);
+        assert!(!world.get::<Visibility>(cursor).unwrap().is_visible);
+
+        // the os cursor is shown again once the software cursor is removed
+        world.despawn(cursor);
+        schedule.run(&mut world);
+        let windows = world.get_resource::<Windows>().unwrap();
+        assert!(windows.get_primary().unwrap().cursor_visible());
+    }
+
+    #[test]
+    fn software_cursor_hides_its_own_window_cursor() {
+        let mut world = World::new();
+        let secondary = WindowId::new();
+        let mut windows = Windows::default();
+        for id in [WindowId::primary(), secondary] {
+            windows.add(Window::new(
+                id,
+                &WindowDescriptor::default(),
+                800,
+                600,
+                1.0,
+                None,
+                RawWindowHandle::Web(WebHandle::empty()),
+            ));
+        }
+        windows
+            .get_mut(secondary)
+            .unwrap()
+            .update_cursor_physical_position_from_backend(Some(DVec2::new(10.0, 20.0)));
+        world.insert_resource(windows);
+
+        let cursor = world
+            .spawn()
+            .insert_bundle((
+                SoftwareCursor {
+                    window: secondary,
+                    ..Default::default()
+                },
+                Style::default(),
+                Visibility { is_visible: false },
+            ))
+            .id();
+
+        let mut schedule = Schedule::default();
+        let mut update_stage = SystemStage::parallel();
+        update_stage.add_system(software_cursor_system);
+        schedule.add_stage("update", update_stage);
+        schedule.run(&mut world);
+
+        // positioned against the secondary window, whose cursor is hidden
+        let style = world.get::<Style>(cursor).unwrap();
+        assert_eq!(style.position.left, Val::Px(10.0));
+        assert_eq!(style.position.top, Val::Px(580.0));
+        assert!(world.get::<Visibility>(cursor).unwrap().is_visible);
+        let windows = world.get_resource::<Windows>().unwrap();
+        assert!(windows.get_primary().unwrap().cursor_visible());
+        assert!(!windows.get(secondary).unwrap().cursor_visible());
+
+        world.despawn(cursor);
+        schedule.run(&mut world);
+        let windows = world.get_resource::<Windows>().unwrap();
+        assert!(windows.get(secondary).unwrap().cursor_visible());
+    }
+}
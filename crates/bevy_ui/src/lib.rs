@@ -1,4 +1,5 @@
 mod anchors;
+mod cursor;
 mod flex;
 mod focus;
 mod margins;
@@ -10,6 +11,7 @@ pub mod update;
 pub mod widget;
 
 pub use anchors::*;
+pub use cursor::*;
 pub use flex::*;
 pub use focus::*;
 pub use margins::*;
@@ -18,7 +20,9 @@ pub use ui_node::*;
 
 pub mod prelude {
     #[doc(hidden)]
-    pub use crate::{entity::*, ui_node::*, widget::Button, Anchors, Interaction, Margins};
+    pub use crate::{
+        entity::*, ui_node::*, widget::Button, Anchors, Interaction, Margins, SoftwareCursor,
+    };
 }
 
 use bevy_app::prelude::*;
@@ -60,6 +64,7 @@ impl Plugin for UiPlugin {
             .register_type::<Size<f32>>()
             .register_type::<Size<Val>>()
             .register_type::<Rect<Val>>()
+            .register_type::<SoftwareCursor>()
             .register_type::<Style>()
             .register_type::<UiColor>()
             .register_type::<UiImage>()
@@ -79,6 +84,10 @@ impl Plugin for UiPlugin {
                 CoreStage::PostUpdate,
                 widget::image_node_system.before(UiSystem::Flex),
             )
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                software_cursor_system.before(UiSystem::Flex),
+            )
             .add_system_to_stage(
                 CoreStage::PostUpdate,
                 flex_node_system
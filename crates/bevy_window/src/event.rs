@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use super::{WindowDescriptor, WindowId};
+use super::{WindowDescriptor, WindowId, WindowMode};
 use bevy_math::{IVec2, Vec2};
 
 /// A window event that is sent whenever a windows logical size has changed
@@ -26,6 +26,13 @@ pub struct CloseWindow {
     pub id: WindowId,
 }
 
+/// An event that is sent whenever a window is closed, after it has been removed from
+/// [`Windows`](crate::Windows).
+#[derive(Debug, Clone)]
+pub struct WindowClosed {
+    pub id: WindowId,
+}
+
 /// An event that is sent whenever a new window is created.
 #[derive(Debug, Clone)]
 pub struct WindowCreated {
@@ -39,6 +46,16 @@ pub struct WindowCloseRequested {
     pub id: WindowId,
 }
 
+/// An event that vetoes a [`WindowCloseRequested`] for the same window, keeping it open.
+///
+/// Send it from a system running before [`ExitOnCloseSystem`](crate::ExitOnCloseSystem) in the
+/// same frame as the request, for example to ask about unsaved changes first. The window can then
+/// be closed later by sending [`CloseWindow`] or [`AppExit`](bevy_app::AppExit) directly.
+#[derive(Debug, Clone)]
+pub struct WindowCloseCancelled {
+    pub id: WindowId,
+}
+
 #[derive(Debug, Clone)]
 pub struct CursorMoved {
     pub id: WindowId,
@@ -69,12 +86,33 @@ pub struct WindowFocused {
     pub focused: bool,
 }
 
+/// An event that indicates a window has been minimized or restored.
+///
+/// See [`Window::is_minimized`](crate::Window::is_minimized) for platform support.
+#[derive(Debug, Clone)]
+pub struct WindowMinimized {
+    pub id: WindowId,
+    pub minimized: bool,
+}
+
 /// An event that indicates a window's scale factor has changed.
 #[derive(Debug, Clone)]
 pub struct WindowScaleFactorChanged {
     pub id: WindowId,
     pub scale_factor: f64,
 }
+
+/// An event that is sent whenever the backend has been asked to switch a window to a new
+/// [`WindowMode`].
+///
+/// On some platforms, such as macOS, the switch completes asynchronously after this event. The
+/// resulting size is reported by a later [`WindowResized`].
+#[derive(Debug, Clone)]
+pub struct WindowModeChanged {
+    pub id: WindowId,
+    pub mode: WindowMode,
+}
+
 /// An event that indicates a window's OS-reported scale factor has changed.
 #[derive(Debug, Clone)]
 pub struct WindowBackendScaleFactorChanged {
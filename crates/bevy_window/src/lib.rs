@@ -15,12 +15,13 @@ pub use windows::*;
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
-        CursorEntered, CursorIcon, CursorLeft, CursorMoved, FileDragAndDrop, ReceivedCharacter,
-        Window, WindowDescriptor, WindowMoved, Windows,
+        CursorEntered, CursorIcon, CursorLeft, CursorMoved, ExitCondition, FileDragAndDrop,
+        ReceivedCharacter, Window, WindowDescriptor, WindowMoved, Windows,
     };
 }
 
 use bevy_app::{prelude::*, Events};
+use bevy_ecs::schedule::ParallelSystemDescriptorCoercion;
 
 pub struct WindowPlugin {
     pub add_primary_window: bool,
@@ -42,16 +43,20 @@ impl Plugin for WindowPlugin {
             .add_event::<CreateWindow>()
             .add_event::<WindowCreated>()
             .add_event::<WindowCloseRequested>()
+            .add_event::<WindowCloseCancelled>()
             .add_event::<CloseWindow>()
+            .add_event::<WindowClosed>()
             .add_event::<CursorMoved>()
             .add_event::<CursorEntered>()
             .add_event::<CursorLeft>()
             .add_event::<ReceivedCharacter>()
             .add_event::<WindowFocused>()
+            .add_event::<WindowMinimized>()
             .add_event::<WindowScaleFactorChanged>()
             .add_event::<WindowBackendScaleFactorChanged>()
             .add_event::<FileDragAndDrop>()
             .add_event::<WindowMoved>()
+            .add_event::<WindowModeChanged>()
             .init_resource::<Windows>();
 
         if self.add_primary_window {
@@ -71,7 +76,8 @@ impl Plugin for WindowPlugin {
         }
 
         if self.exit_on_close {
-            app.add_system(exit_on_window_close_system);
+            app.init_resource::<ExitCondition>()
+                .add_system(exit_on_window_close_system.label(ExitOnCloseSystem));
         }
     }
 }
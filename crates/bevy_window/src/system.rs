@@ -1,11 +1,250 @@
-use crate::WindowCloseRequested;
+use crate::{
+    CloseWindow, WindowCloseCancelled, WindowCloseRequested, WindowClosed, WindowId, Windows,
+};
 use bevy_app::{AppExit, EventReader, EventWriter};
+use bevy_ecs::{schedule::SystemLabel, system::Res};
+use bevy_utils::HashSet;
+
+/// The label of [`exit_on_window_close_system`]. Systems that send [`WindowCloseCancelled`]
+/// should run before it.
+#[derive(Debug, PartialEq, Eq, Clone, Hash, SystemLabel)]
+pub struct ExitOnCloseSystem;
+
+/// Selects when [`exit_on_window_close_system`] exits the app.
+///
+/// Insert it as a resource before adding the [`WindowPlugin`](crate::WindowPlugin) to change
+/// the default. Close requests that do not exit the app close the requested window instead,
+/// unless they are vetoed with a [`WindowCloseCancelled`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExitCondition {
+    /// Exit as soon as a close is requested for any window.
+    OnAnyCloseRequested,
+    /// Exit when a close is requested for the primary window.
+    OnPrimaryCloseRequested,
+    /// Exit when a close is requested for the given window.
+    OnCloseRequested(WindowId),
+    /// Exit once every window has been closed.
+    OnAllClosed,
+}
+
+impl Default for ExitCondition {
+    fn default() -> Self {
+        ExitCondition::OnAnyCloseRequested
+    }
+}
 
 pub fn exit_on_window_close_system(
+    exit_condition: Res<ExitCondition>,
+    windows: Res<Windows>,
     mut app_exit_events: EventWriter<AppExit>,
+    mut close_window_events: EventWriter<CloseWindow>,
     mut window_close_requested_events: EventReader<WindowCloseRequested>,
+    mut window_close_cancelled_events: EventReader<WindowCloseCancelled>,
+    mut window_closed_events: EventReader<WindowClosed>,
 ) {
-    if window_close_requested_events.iter().next().is_some() {
+    let cancelled: HashSet<WindowId> = window_close_cancelled_events
+        .iter()
+        .map(|event| event.id)
+        .collect();
+    for event in window_close_requested_events.iter() {
+        if cancelled.contains(&event.id) {
+            continue;
+        }
+        let exit = match *exit_condition {
+            ExitCondition::OnAnyCloseRequested => true,
+            ExitCondition::OnPrimaryCloseRequested => event.id.is_primary(),
+            ExitCondition::OnCloseRequested(id) => event.id == id,
+            ExitCondition::OnAllClosed => false,
+        };
+        if exit {
+            app_exit_events.send(AppExit);
+            return;
+        }
+        close_window_events.send(CloseWindow { id: event.id });
+    }
+
+    if *exit_condition == ExitCondition::OnAllClosed
+        && window_closed_events.iter().next().is_some()
+        && windows.iter().next().is_none()
+    {
         app_exit_events.send(AppExit);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Window, WindowDescriptor};
+    use bevy_app::Events;
+    use bevy_ecs::{
+        schedule::{Stage, SystemStage},
+        system::Resource,
+        world::World,
+    };
+    use raw_window_handle::{RawWindowHandle, WebHandle};
+
+    fn add_window(world: &mut World, id: WindowId) {
+        let window = Window::new(
+            id,
+            &WindowDescriptor::default(),
+            800,
+            600,
+            1.0,
+            None,
+            RawWindowHandle::Web(WebHandle::empty()),
+        );
+        world.get_resource_mut::<Windows>().unwrap().add(window);
+    }
+
+    /// Closes a window the way the backend does in response to `CloseWindow`.
+    fn close_window(world: &mut World, id: WindowId) {
+        world.get_resource_mut::<Windows>().unwrap().remove(id);
+        world
+            .get_resource_mut::<Events<WindowClosed>>()
+            .unwrap()
+            .send(WindowClosed { id });
+    }
+
+    fn send<T: Resource>(world: &mut World, event: T) {
+        world.get_resource_mut::<Events<T>>().unwrap().send(event);
+    }
+
+    fn drain<T: Resource>(world: &mut World) -> Vec<T> {
+        world
+            .get_resource_mut::<Events<T>>()
+            .unwrap()
+            .drain()
+            .collect()
+    }
+
+    fn setup(exit_condition: ExitCondition, secondary: WindowId) -> (World, SystemStage) {
+        let mut world = World::new();
+        world.insert_resource(exit_condition);
+        world.insert_resource(Windows::default());
+        world.insert_resource(Events::<AppExit>::default());
+        world.insert_resource(Events::<CloseWindow>::default());
+        world.insert_resource(Events::<WindowCloseRequested>::default());
+        world.insert_resource(Events::<WindowCloseCancelled>::default());
+        world.insert_resource(Events::<WindowClosed>::default());
+        add_window(&mut world, WindowId::primary());
+        add_window(&mut world, secondary);
+
+        let mut stage = SystemStage::single_threaded();
+        stage.add_system(exit_on_window_close_system);
+        (world, stage)
+    }
+
+    #[test]
+    fn exit_on_any_close_requested() {
+        let secondary = WindowId::new();
+        let (mut world, mut stage) = setup(ExitCondition::OnAnyCloseRequested, secondary);
+
+        send(&mut world, WindowCloseRequested { id: secondary });
+        stage.run(&mut world);
+        assert_eq!(drain::<AppExit>(&mut world).len(), 1);
+    }
+
+    #[test]
+    fn exit_on_primary_close_requested() {
+        let secondary = WindowId::new();
+        let (mut world, mut stage) = setup(ExitCondition::OnPrimaryCloseRequested, secondary);
+
+        // other windows are closed instead
+        send(&mut world, WindowCloseRequested { id: secondary });
+        stage.run(&mut world);
+        assert!(drain::<AppExit>(&mut world).is_empty());
+        let closed = drain::<CloseWindow>(&mut world);
+        assert_eq!(closed.len(), 1);
+        assert_eq!(closed[0].id, secondary);
+
+        send(
+            &mut world,
+            WindowCloseRequested {
+                id: WindowId::primary(),
+            },
+        );
+        stage.run(&mut world);
+        assert_eq!(drain::<AppExit>(&mut world).len(), 1);
+        assert!(drain::<CloseWindow>(&mut world).is_empty());
+    }
+
+    #[test]
+    fn exit_on_given_window_close_requested() {
+        let secondary = WindowId::new();
+        let (mut world, mut stage) = setup(ExitCondition::OnCloseRequested(secondary), secondary);
+
+        send(
+            &mut world,
+            WindowCloseRequested {
+                id: WindowId::primary(),
+            },
+        );
+        stage.run(&mut world);
+        assert!(drain::<AppExit>(&mut world).is_empty());
+        assert_eq!(drain::<CloseWindow>(&mut world).len(), 1);
+
+        send(&mut world, WindowCloseRequested { id: secondary });
+        stage.run(&mut world);
+        assert_eq!(drain::<AppExit>(&mut world).len(), 1);
+    }
+
+    #[test]
+    fn exit_on_all_closed() {
+        let secondary = WindowId::new();
+        let (mut world, mut stage) = setup(ExitCondition::OnAllClosed, secondary);
+
+        send(&mut world, WindowCloseRequested { id: secondary });
+        stage.run(&mut world);
+        assert!(drain::<AppExit>(&mut world).is_empty());
+        assert_eq!(drain::<CloseWindow>(&mut world).len(), 1);
+
+        // the primary window is still open
+        close_window(&mut world, secondary);
+        stage.run(&mut world);
+        assert!(drain::<AppExit>(&mut world).is_empty());
+
+        send(
+            &mut world,
+            WindowCloseRequested {
+                id: WindowId::primary(),
+            },
+        );
+        stage.run(&mut world);
+        assert!(drain::<AppExit>(&mut world).is_empty());
+        assert_eq!(drain::<CloseWindow>(&mut world).len(), 1);
+
+        close_window(&mut world, WindowId::primary());
+        stage.run(&mut world);
+        assert_eq!(drain::<AppExit>(&mut world).len(), 1);
+    }
+
+    #[test]
+    fn cancelled_close_requests_are_ignored() {
+        let secondary = WindowId::new();
+        for exit_condition in [
+            ExitCondition::OnAnyCloseRequested,
+            ExitCondition::OnPrimaryCloseRequested,
+            ExitCondition::OnAllClosed,
+        ] {
+            let (mut world, mut stage) = setup(exit_condition, secondary);
+
+            send(
+                &mut world,
+                WindowCloseRequested {
+                    id: WindowId::primary(),
+                },
+            );
+            send(&mut world, WindowCloseRequested { id: secondary });
+            send(&mut world, WindowCloseCancelled { id: secondary });
+            send(
+                &mut world,
+                WindowCloseCancelled {
+                    id: WindowId::primary(),
+                },
+            );
+            stage.run(&mut world);
+            assert!(drain::<AppExit>(&mut world).is_empty());
+            assert!(drain::<CloseWindow>(&mut world).is_empty());
+        }
+    }
+}
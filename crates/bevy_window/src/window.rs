@@ -127,10 +127,12 @@ pub struct Window {
     cursor_icon: CursorIcon,
     cursor_visible: bool,
     cursor_locked: bool,
+    cursor_position_before_lock: Option<Vec2>,
     physical_cursor_position: Option<DVec2>,
     raw_window_handle: RawWindowHandleWrapper,
     focused: bool,
     mode: WindowMode,
+    content_protected: bool,
     #[cfg(target_arch = "wasm32")]
     pub canvas: Option<String>,
     command_queue: Vec<WindowCommand>,
@@ -185,6 +187,9 @@ pub enum WindowCommand {
     SetResizeConstraints {
         resize_constraints: WindowResizeConstraints,
     },
+    SetContentProtected {
+        content_protected: bool,
+    },
 }
 
 /// Defines the way a window is displayed
@@ -227,11 +232,14 @@ impl Window {
             decorations: window_descriptor.decorations,
             cursor_visible: window_descriptor.cursor_visible,
             cursor_locked: window_descriptor.cursor_locked,
+            cursor_position_before_lock: None,
             cursor_icon: CursorIcon::Default,
             physical_cursor_position: None,
             raw_window_handle: RawWindowHandleWrapper::new(raw_window_handle),
-            focused: true,
+            // the backend reports focus once the window has it
+            focused: false,
             mode: window_descriptor.mode,
+            content_protected: false,
             #[cfg(target_arch = "wasm32")]
             canvas: window_descriptor.canvas.clone(),
             command_queue: Vec::new(),
@@ -287,6 +295,15 @@ impl Window {
         self.physical_height
     }
 
+    /// Whether the window is minimized. Minimized windows are not rendered to.
+    ///
+    /// This relies on the backend reporting minimized windows as zero-sized, as it does on
+    /// Windows. On other platforms minimized windows keep their size, and are still rendered.
+    #[inline]
+    pub fn is_minimized(&self) -> bool {
+        self.physical_width == 0 || self.physical_height == 0
+    }
+
     /// The window's client resize constraint in logical pixels.
     #[inline]
     pub fn resize_constraints(&self) -> WindowResizeConstraints {
@@ -321,7 +338,8 @@ impl Window {
     /// Note that the top-left hand corner of the desktop is not necessarily the same as the screen.
     /// If the user uses a desktop with multiple monitors, the top-left hand corner of the
     /// desktop is the top-left hand corner of the monitor at the top-left of the desktop. This
-    /// automatically un-maximizes the window if it's maximized.
+    /// automatically un-maximizes the window if it's maximized. If the position is not on any
+    /// available monitor, the window is moved onto the primary monitor instead.
     ///
     /// # Platform-specific
     ///
@@ -457,15 +475,46 @@ impl Window {
             .push(WindowCommand::SetDecorations { decorations });
     }
 
+    /// Whether the window backend has excluded the window's contents from screen captures. This
+    /// only changes once the backend has applied a [`Window::set_content_protected`] request.
+    #[inline]
+    pub fn content_protected(&self) -> bool {
+        self.content_protected
+    }
+
+    /// Requests that the window's contents are excluded from screen captures and recordings.
+    ///
+    /// No window backend currently supports this: the request is ignored with a warning, and
+    /// [`Window::content_protected`] stays `false`.
+    pub fn set_content_protected(&mut self, content_protected: bool) {
+        self.command_queue
+            .push(WindowCommand::SetContentProtected { content_protected });
+    }
+
     #[inline]
     pub fn cursor_locked(&self) -> bool {
         self.cursor_locked
     }
 
+    /// Grabs or releases the cursor.
+    ///
+    /// Releasing the cursor moves it back to where it was when it was grabbed, on platforms that
+    /// support setting the cursor position.
     pub fn set_cursor_lock_mode(&mut self, lock_mode: bool) {
+        if lock_mode && !self.cursor_locked {
+            self.cursor_position_before_lock = self.cursor_position();
+        }
+        let restore_position = if !lock_mode {
+            self.cursor_position_before_lock.take()
+        } else {
+            None
+        };
         self.cursor_locked = lock_mode;
         self.command_queue
             .push(WindowCommand::SetCursorLockMode { locked: lock_mode });
+        if let Some(position) = restore_position {
+            self.set_cursor_position(position);
+        }
     }
 
     #[inline]
@@ -515,6 +564,12 @@ impl Window {
         self.focused = focused;
     }
 
+    #[allow(missing_docs)]
+    #[inline]
+    pub fn update_content_protected_from_backend(&mut self, content_protected: bool) {
+        self.content_protected = content_protected;
+    }
+
     #[allow(missing_docs)]
     #[inline]
     pub fn update_cursor_physical_position_from_backend(&mut self, cursor_position: Option<DVec2>) {
@@ -539,6 +594,8 @@ impl Window {
         self.command_queue.drain(..)
     }
 
+    /// Whether the window has input focus. Windows are created unfocused until the backend
+    /// reports that they gained focus.
     #[inline]
     pub fn is_focused(&self) -> bool {
         self.focused
@@ -553,6 +610,12 @@ impl Window {
 pub struct WindowDescriptor {
     pub width: f32,
     pub height: f32,
+    /// The logical position of the window's top-left corner. If it is not on any available
+    /// monitor, the window is moved onto the primary monitor instead.
+    ///
+    /// [`Window::position`] and [`WindowMoved`](crate::WindowMoved) report physical pixels. To
+    /// restore a saved position, divide it by the scale factor, or pass it to
+    /// [`Window::set_position`] once the window is created.
     pub position: Option<Vec2>,
     pub resize_constraints: WindowResizeConstraints,
     pub scale_factor_override: Option<f64>,
@@ -596,3 +659,71 @@ impl Default for WindowDescriptor {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use raw_window_handle::WebHandle;
+
+    #[test]
+    fn releasing_cursor_grab_restores_position() {
+        let mut window = Window::new(
+            WindowId::primary(),
+            &WindowDescriptor::default(),
+            800,
+            600,
+            2.0,
+            None,
+            RawWindowHandle::Web(WebHandle::empty()),
+        );
+        window.update_cursor_physical_position_from_backend(Some(DVec2::new(200.0, 100.0)));
+        window.set_cursor_lock_mode(true);
+        // locking again does not overwrite the position to restore
+        window.update_cursor_physical_position_from_backend(Some(DVec2::new(400.0, 300.0)));
+        window.set_cursor_lock_mode(true);
+        window.drain_commands().for_each(drop);
+
+        window.set_cursor_lock_mode(false);
+        let commands: Vec<_> = window.drain_commands().collect();
+        assert!(matches!(
+            commands[..],
+            [
+                WindowCommand::SetCursorLockMode { locked: false },
+                WindowCommand::SetCursorPosition { position },
+            ] if position == Vec2::new(100.0, 50.0)
+        ));
+
+        // nothing is restored without a grab
+        window.set_cursor_lock_mode(false);
+        let commands: Vec<_> = window.drain_commands().collect();
+        assert!(matches!(
+            commands[..],
+            [WindowCommand::SetCursorLockMode { locked: false }]
+        ));
+    }
+
+    #[test]
+    fn content_protection_waits_for_backend() {
+        let mut window = Window::new(
+            WindowId::primary(),
+            &WindowDescriptor::default(),
+            800,
+            600,
+            1.0,
+            None,
+            RawWindowHandle::Web(WebHandle::empty()),
+        );
+        window.set_content_protected(true);
+        let commands: Vec<_> = window.drain_commands().collect();
+        assert!(matches!(
+            commands[..],
+            [WindowCommand::SetContentProtected {
+                content_protected: true
+            }]
+        ));
+        assert!(!window.content_protected());
+
+        window.update_content_protected_from_backend(true);
+        assert!(window.content_protected());
+    }
+}
@@ -11,6 +11,10 @@ impl Windows {
         self.windows.insert(window.id(), window);
     }
 
+    pub fn remove(&mut self, id: WindowId) -> Option<Window> {
+        self.windows.remove(&id)
+    }
+
     pub fn get(&self, id: WindowId) -> Option<&Window> {
         self.windows.get(&id)
     }
@@ -27,6 +31,16 @@ impl Windows {
         self.get_mut(WindowId::primary())
     }
 
+    /// Returns the window that currently has input focus, or `None` while focus is outside of
+    /// this app's windows or the backend has not reported focus yet.
+    pub fn get_focused(&self) -> Option<&Window> {
+        self.windows.values().find(|window| window.is_focused())
+    }
+
+    pub fn get_focused_mut(&mut self) -> Option<&mut Window> {
+        self.windows.values_mut().find(|window| window.is_focused())
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = &Window> {
         self.windows.values()
     }
@@ -35,3 +49,42 @@ impl Windows {
         self.windows.values_mut()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WindowDescriptor;
+    use raw_window_handle::{RawWindowHandle, WebHandle};
+
+    #[test]
+    fn get_focused_follows_backend_focus() {
+        let mut windows = Windows::default();
+        let secondary = WindowId::new();
+        for id in [WindowId::primary(), secondary] {
+            windows.add(Window::new(
+                id,
+                &WindowDescriptor::default(),
+                800,
+                600,
+                1.0,
+                None,
+                RawWindowHandle::Web(WebHandle::empty()),
+            ));
+        }
+        // no window is focused until the backend says so
+        assert!(windows.get_focused().is_none());
+
+        windows
+            .get_mut(secondary)
+            .unwrap()
+            .update_focused_status_from_backend(true);
+        assert_eq!(windows.get_focused().unwrap().id(), secondary);
+
+        // focus moved to a window outside of the app
+        windows
+            .get_mut(secondary)
+            .unwrap()
+            .update_focused_status_from_backend(false);
+        assert!(windows.get_focused().is_none());
+    }
+}
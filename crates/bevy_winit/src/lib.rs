@@ -13,11 +13,12 @@ pub use winit_windows::*;
 use bevy_app::{App, AppExit, CoreStage, Events, ManualEventReader, Plugin};
 use bevy_ecs::{system::IntoExclusiveSystem, world::World};
 use bevy_math::{ivec2, DVec2, Vec2};
-use bevy_utils::tracing::{error, trace, warn};
+use bevy_utils::tracing::{debug, error, trace, warn};
 use bevy_window::{
-    CreateWindow, CursorEntered, CursorLeft, CursorMoved, FileDragAndDrop, ReceivedCharacter,
-    WindowBackendScaleFactorChanged, WindowCloseRequested, WindowCreated, WindowFocused,
-    WindowMoved, WindowResized, WindowScaleFactorChanged, Windows,
+    CloseWindow, CreateWindow, CursorEntered, CursorLeft, CursorMoved, FileDragAndDrop,
+    ReceivedCharacter, WindowBackendScaleFactorChanged, WindowCloseRequested, WindowClosed,
+    WindowCreated, WindowFocused, WindowId, WindowMinimized, WindowModeChanged, WindowMoved,
+    WindowResized, WindowScaleFactorChanged, Windows,
 };
 use winit::{
     dpi::PhysicalPosition,
@@ -43,7 +44,7 @@ impl Plugin for WinitPlugin {
 
 fn change_window(world: &mut World) {
     let world = world.cell();
-    let winit_windows = world.get_resource::<WinitWindows>().unwrap();
+    let mut winit_windows = world.get_resource_mut::<WinitWindows>().unwrap();
     let mut windows = world.get_resource_mut::<Windows>().unwrap();
 
     for bevy_window in windows.iter_mut() {
@@ -55,6 +56,12 @@ fn change_window(world: &mut World) {
                     resolution: (width, height),
                 } => {
                     let window = winit_windows.get_window(id).unwrap();
+                    // remember the windowed geometry, so it can be restored when leaving fullscreen
+                    let windowed_geometry = if window.fullscreen().is_none() {
+                        Some((window.inner_size(), window.outer_position().ok()))
+                    } else {
+                        None
+                    };
                     match mode {
                         bevy_window::WindowMode::BorderlessFullscreen => {
                             window.set_fullscreen(Some(winit::window::Fullscreen::Borderless(None)))
@@ -71,8 +78,29 @@ fn change_window(world: &mut World) {
                                 height,
                             )),
                         )),
-                        bevy_window::WindowMode::Windowed => window.set_fullscreen(None),
+                        bevy_window::WindowMode::Windowed => {
+                            window.set_fullscreen(None);
+                            if let Some((size, position)) = winit_windows.windowed_geometry.get(&id)
+                            {
+                                window.set_inner_size(*size);
+                                if let Some(position) = position {
+                                    window.set_outer_position(*position);
+                                }
+                            }
+                        }
                     }
+                    if mode == bevy_window::WindowMode::Windowed {
+                        winit_windows.windowed_geometry.remove(&id);
+                    } else if let Some(windowed_geometry) = windowed_geometry {
+                        winit_windows
+                            .windowed_geometry
+                            .insert(id, windowed_geometry);
+                    }
+
+                    let mut window_mode_changed_events = world
+                        .get_resource_mut::<Events<WindowModeChanged>>()
+                        .unwrap();
+                    window_mode_changed_events.send(WindowModeChanged { id, mode });
                 }
                 bevy_window::WindowCommand::SetTitle { title } => {
                     let window = winit_windows.get_window(id).unwrap();
@@ -120,12 +148,17 @@ fn change_window(world: &mut World) {
                 bevy_window::WindowCommand::SetCursorPosition { position } => {
                     let window = winit_windows.get_window(id).unwrap();
                     let inner_size = window.inner_size().to_logical::<f32>(window.scale_factor());
-                    window
-                        .set_cursor_position(winit::dpi::LogicalPosition::new(
-                            position.x,
-                            inner_size.height - position.y,
-                        ))
-                        .unwrap_or_else(|e| error!("Unable to set cursor position: {}", e));
+                    match window.set_cursor_position(winit::dpi::LogicalPosition::new(
+                        position.x,
+                        inner_size.height - position.y,
+                    )) {
+                        Ok(_) => {}
+                        // e.g. Wayland, where releasing a cursor grab also tries to restore it
+                        Err(winit::error::ExternalError::NotSupported(_)) => {
+                            debug!("Setting the cursor position is not supported by the platform")
+                        }
+                        Err(e) => error!("Unable to set cursor position: {}", e),
+                    }
                 }
                 bevy_window::WindowCommand::SetMaximized { maximized } => {
                     let window = winit_windows.get_window(id).unwrap();
@@ -137,6 +170,23 @@ fn change_window(world: &mut World) {
                 }
                 bevy_window::WindowCommand::SetPosition { position } => {
                     let window = winit_windows.get_window(id).unwrap();
+                    let size = window.outer_size();
+                    let monitors =
+                        monitor_rects(window.available_monitors(), window.primary_monitor());
+                    let position = match clamp_to_monitors(
+                        position,
+                        ivec2(size.width as i32, size.height as i32),
+                        &monitors,
+                    ) {
+                        Some(clamped_position) => {
+                            warn!(
+                                "Window position {} is outside of all monitors, moving the window to {}",
+                                position, clamped_position
+                            );
+                            clamped_position
+                        }
+                        None => position,
+                    };
                     window.set_outer_position(PhysicalPosition {
                         x: position[0],
                         y: position[1],
@@ -159,6 +209,15 @@ fn change_window(world: &mut World) {
                         window.set_max_inner_size(Some(max_inner_size));
                     }
                 }
+                bevy_window::WindowCommand::SetContentProtected { content_protected } => {
+                    // winit does not expose content protection on any platform yet
+                    if content_protected {
+                        warn!(
+                            "Content protection is not supported by the window backend, ignoring it for {:?}",
+                            id
+                        );
+                    }
+                }
             }
         }
     }
@@ -225,6 +284,7 @@ pub fn winit_runner(app: App) {
 pub fn winit_runner_with(mut app: App) {
     let mut event_loop = app.world.remove_non_send::<EventLoop<()>>().unwrap();
     let mut create_window_event_reader = ManualEventReader::<CreateWindow>::default();
+    let mut close_window_event_reader = ManualEventReader::<CloseWindow>::default();
     let mut app_exit_event_reader = ManualEventReader::<AppExit>::default();
     app.world.insert_non_send(event_loop.create_proxy());
 
@@ -236,6 +296,9 @@ pub fn winit_runner_with(mut app: App) {
         .map_or(false, |config| config.return_from_run);
 
     let mut active = true;
+    // windows resized since the last update. the backend can report many sizes per frame during
+    // a resize drag, but only the final size is sent as a `WindowResized` event
+    let mut resized_windows = Vec::new();
 
     let event_handler = move |event: Event<()>,
                               event_loop: &EventLoopWindowTarget<()>,
@@ -281,14 +344,17 @@ pub fn winit_runner_with(mut app: App) {
 
                 match event {
                     WindowEvent::Resized(size) => {
-                        window.update_actual_size_from_backend(size.width, size.height);
-                        let mut resize_events =
-                            world.get_resource_mut::<Events<WindowResized>>().unwrap();
-                        resize_events.send(WindowResized {
-                            id: window_id,
-                            width: window.width(),
-                            height: window.height(),
-                        });
+                        // release the borrows so the helper can take the whole world
+                        drop(windows);
+                        drop(winit_windows);
+                        drop(world);
+                        handle_window_resized(
+                            &mut app.world,
+                            &mut resized_windows,
+                            window_id,
+                            size.width,
+                            size.height,
+                        );
                     }
                     WindowEvent::CloseRequested => {
                         let mut window_close_requested_events = world
@@ -368,7 +434,7 @@ pub fn winit_runner_with(mut app: App) {
                         // On a mobile window, the start is from the top while on PC/Linux/OSX from
                         // bottom
                         if cfg!(target_os = "android") || cfg!(target_os = "ios") {
-                            let window_height = windows.get_primary().unwrap().height();
+                            let window_height = window.height();
                             location.y = window_height - location.y;
                         }
                         touch_input_events.send(converters::convert_touch_input(touch, location));
@@ -420,16 +486,11 @@ pub fn winit_runner_with(mut app: App) {
 
                         let new_logical_width = new_inner_size.width as f64 / new_factor;
                         let new_logical_height = new_inner_size.height as f64 / new_factor;
-                        if approx::relative_ne!(window.width() as f64, new_logical_width)
-                            || approx::relative_ne!(window.height() as f64, new_logical_height)
+                        if (approx::relative_ne!(window.width() as f64, new_logical_width)
+                            || approx::relative_ne!(window.height() as f64, new_logical_height))
+                            && !resized_windows.contains(&window_id)
                         {
-                            let mut resize_events =
-                                world.get_resource_mut::<Events<WindowResized>>().unwrap();
-                            resize_events.send(WindowResized {
-                                id: window_id,
-                                width: new_logical_width as f32,
-                                height: new_logical_height as f32,
-                            });
+                            resized_windows.push(window_id);
                         }
                         window.update_actual_size_from_backend(
                             new_inner_size.width,
@@ -495,11 +556,13 @@ pub fn winit_runner_with(mut app: App) {
                 active = true;
             }
             event::Event::MainEventsCleared => {
+                send_resize_events(&mut app.world, &mut resized_windows);
                 handle_create_window_events(
                     &mut app.world,
                     event_loop,
                     &mut create_window_event_reader,
                 );
+                handle_close_window_events(&mut app.world, &mut close_window_event_reader);
                 if active {
                     app.update();
                 }
@@ -514,6 +577,53 @@ pub fn winit_runner_with(mut app: App) {
     }
 }
 
+fn handle_window_resized(
+    world: &mut World,
+    resized_windows: &mut Vec<WindowId>,
+    id: WindowId,
+    width: u32,
+    height: u32,
+) {
+    let world = world.cell();
+    let mut windows = world.get_resource_mut::<Windows>().unwrap();
+    let window = if let Some(window) = windows.get_mut(id) {
+        window
+    } else {
+        return;
+    };
+    let was_minimized = window.is_minimized();
+    window.update_actual_size_from_backend(width, height);
+    if window.is_minimized() != was_minimized {
+        let mut minimized_events = world.get_resource_mut::<Events<WindowMinimized>>().unwrap();
+        minimized_events.send(WindowMinimized {
+            id,
+            minimized: window.is_minimized(),
+        });
+    }
+    if !resized_windows.contains(&id) {
+        resized_windows.push(id);
+    }
+}
+
+fn send_resize_events(world: &mut World, resized_windows: &mut Vec<WindowId>) {
+    if resized_windows.is_empty() {
+        return;
+    }
+
+    let world = world.cell();
+    let windows = world.get_resource::<Windows>().unwrap();
+    let mut resize_events = world.get_resource_mut::<Events<WindowResized>>().unwrap();
+    for id in resized_windows.drain(..) {
+        if let Some(window) = windows.get(id) {
+            resize_events.send(WindowResized {
+                id,
+                width: window.width(),
+                height: window.height(),
+            });
+        }
+    }
+}
+
 fn handle_create_window_events(
     world: &mut World,
     event_loop: &EventLoopWindowTarget<()>,
@@ -537,6 +647,25 @@ fn handle_create_window_events(
     }
 }
 
+fn handle_close_window_events(
+    world: &mut World,
+    close_window_event_reader: &mut ManualEventReader<CloseWindow>,
+) {
+    let world = world.cell();
+    let mut winit_windows = world.get_resource_mut::<WinitWindows>().unwrap();
+    let mut windows = world.get_resource_mut::<Windows>().unwrap();
+    let close_window_events = world.get_resource::<Events<CloseWindow>>().unwrap();
+    let mut window_closed_events = world.get_resource_mut::<Events<WindowClosed>>().unwrap();
+    for close_window_event in close_window_event_reader.iter(&close_window_events) {
+        let id = close_window_event.id;
+        // dropping the winit window closes it
+        if winit_windows.remove_window(id).is_some() {
+            windows.remove(id);
+            window_closed_events.send(WindowClosed { id });
+        }
+    }
+}
+
 fn handle_initial_window_events(world: &mut World, event_loop: &EventLoop<()>) {
     let world = world.cell();
     let mut winit_windows = world.get_resource_mut::<WinitWindows>().unwrap();
@@ -555,3 +684,49 @@ fn handle_initial_window_events(world: &mut World, event_loop: &EventLoop<()>) {
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_window::{Window, WindowDescriptor};
+    use raw_window_handle::{RawWindowHandle, WebHandle};
+
+    #[test]
+    fn resizes_within_a_frame_send_one_event() {
+        let mut world = World::new();
+        world.insert_resource(Events::<WindowResized>::default());
+        world.insert_resource(Events::<WindowMinimized>::default());
+        let mut windows = Windows::default();
+        windows.add(Window::new(
+            WindowId::primary(),
+            &WindowDescriptor::default(),
+            800,
+            600,
+            1.0,
+            None,
+            RawWindowHandle::Web(WebHandle::empty()),
+        ));
+        world.insert_resource(windows);
+
+        let mut resized_windows = Vec::new();
+        for size in [810, 820, 830, 840, 850] {
+            handle_window_resized(
+                &mut world,
+                &mut resized_windows,
+                WindowId::primary(),
+                size,
+                size / 2,
+            );
+        }
+        send_resize_events(&mut world, &mut resized_windows);
+        assert!(resized_windows.is_empty());
+
+        let resize_events = world.get_resource::<Events<WindowResized>>().unwrap();
+        let mut reader = ManualEventReader::<WindowResized>::default();
+        let sent: Vec<_> = reader.iter(resize_events).collect();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].id, WindowId::primary());
+        assert_eq!(sent[0].width, 850.0);
+        assert_eq!(sent[0].height, 425.0);
+    }
+}
@@ -1,14 +1,17 @@
-use bevy_math::IVec2;
-use bevy_utils::HashMap;
+use bevy_math::{ivec2, IVec2, Vec2};
+use bevy_utils::{tracing::warn, HashMap};
 use bevy_window::{Window, WindowDescriptor, WindowId, WindowMode};
 use raw_window_handle::HasRawWindowHandle;
-use winit::dpi::LogicalSize;
+use winit::dpi::{LogicalSize, PhysicalPosition, PhysicalSize};
 
 #[derive(Debug, Default)]
 pub struct WinitWindows {
     pub windows: HashMap<winit::window::WindowId, winit::window::Window>,
     pub window_id_to_winit: HashMap<WindowId, winit::window::WindowId>,
     pub winit_to_window_id: HashMap<winit::window::WindowId, WindowId>,
+    /// Size and position of windows before they went fullscreen.
+    pub(crate) windowed_geometry:
+        HashMap<WindowId, (PhysicalSize<u32>, Option<PhysicalPosition<i32>>)>,
 }
 
 impl WinitWindows {
@@ -53,7 +56,24 @@ impl WinitWindows {
                 } = window_descriptor;
 
                 if let Some(position) = position {
-                    if let Some(sf) = scale_factor_override {
+                    let scale_factor = scale_factor_override
+                        .or_else(|| event_loop.primary_monitor().map(|m| m.scale_factor()))
+                        .unwrap_or(1.0);
+                    let monitors =
+                        monitor_rects(event_loop.available_monitors(), event_loop.primary_monitor());
+                    if let Some(clamped_position) = clamp_to_monitors(
+                        (position.as_dvec2() * scale_factor).as_ivec2(),
+                        (Vec2::new(*width, *height).as_dvec2() * scale_factor).as_ivec2(),
+                        &monitors,
+                    ) {
+                        warn!(
+                            "Window position {} is outside of all monitors, moving the window to {}",
+                            position, clamped_position
+                        );
+                        winit_window_builder = winit_window_builder.with_position(
+                            PhysicalPosition::new(clamped_position.x, clamped_position.y),
+                        );
+                    } else if let Some(sf) = scale_factor_override {
                         winit_window_builder = winit_window_builder.with_position(
                             winit::dpi::LogicalPosition::new(
                                 position[0] as f64,
@@ -179,11 +199,74 @@ impl WinitWindows {
             .and_then(|id| self.windows.get(id))
     }
 
+    pub fn remove_window(&mut self, id: WindowId) -> Option<winit::window::Window> {
+        let winit_id = self.window_id_to_winit.remove(&id)?;
+        self.winit_to_window_id.remove(&winit_id);
+        self.windowed_geometry.remove(&id);
+        self.windows.remove(&winit_id)
+    }
+
     pub fn get_window_id(&self, id: winit::window::WindowId) -> Option<WindowId> {
         self.winit_to_window_id.get(&id).cloned()
     }
 }
 
+/// The area of a monitor in physical pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct MonitorRect {
+    pub position: IVec2,
+    pub size: IVec2,
+}
+
+impl MonitorRect {
+    fn from_handle(monitor: &winit::monitor::MonitorHandle) -> Self {
+        MonitorRect {
+            position: ivec2(monitor.position().x, monitor.position().y),
+            size: ivec2(monitor.size().width as i32, monitor.size().height as i32),
+        }
+    }
+
+    fn contains(&self, point: IVec2) -> bool {
+        point.cmpge(self.position).all() && point.cmplt(self.position + self.size).all()
+    }
+}
+
+/// Collects the rects of the `available` monitors, starting with the `primary` one.
+pub(crate) fn monitor_rects(
+    available: impl Iterator<Item = winit::monitor::MonitorHandle>,
+    primary: Option<winit::monitor::MonitorHandle>,
+) -> Vec<MonitorRect> {
+    let primary = primary.map(|monitor| MonitorRect::from_handle(&monitor));
+    primary
+        .into_iter()
+        .chain(
+            available
+                .map(|monitor| MonitorRect::from_handle(&monitor))
+                .filter(|rect| Some(*rect) != primary),
+        )
+        .collect()
+}
+
+/// Returns a position on the first of `monitors` for a window of `size` whose `position` is not on
+/// any of them, for example because it was saved while the window was on a monitor that has since
+/// been disconnected. All values are in physical pixels.
+///
+/// Returns `None` if the position is fine as it is, or if there are no monitors to clamp to, as
+/// some platforms never report any.
+pub(crate) fn clamp_to_monitors(
+    position: IVec2,
+    size: IVec2,
+    monitors: &[MonitorRect],
+) -> Option<IVec2> {
+    let monitor = monitors.first()?;
+    if monitors.iter().any(|monitor| monitor.contains(position)) {
+        return None;
+    }
+    // keep as much of the window on the monitor as possible
+    let max = monitor.position + (monitor.size - size).max(IVec2::ZERO);
+    Some(position.clamp(monitor.position, max))
+}
+
 pub fn get_fitting_videomode(
     monitor: &winit::monitor::MonitorHandle,
     width: u32,
@@ -235,3 +318,43 @@ pub fn get_best_videomode(monitor: &winit::monitor::MonitorHandle) -> winit::mon
 unsafe impl Send for WinitWindows {}
 #[cfg(target_arch = "wasm32")]
 unsafe impl Sync for WinitWindows {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn positions_off_all_monitors_are_clamped() {
+        let monitors = [
+            MonitorRect {
+                position: ivec2(0, 0),
+                size: ivec2(1920, 1080),
+            },
+            MonitorRect {
+                position: ivec2(1920, 0),
+                size: ivec2(2560, 1440),
+            },
+        ];
+        let size = ivec2(800, 600);
+
+        // on either monitor
+        assert_eq!(clamp_to_monitors(ivec2(100, 100), size, &monitors), None);
+        assert_eq!(clamp_to_monitors(ivec2(3000, 1200), size, &monitors), None);
+        // saved on a third monitor to the right, moved onto the first one but kept inside it
+        assert_eq!(
+            clamp_to_monitors(ivec2(5000, 200), size, &monitors),
+            Some(ivec2(1120, 200))
+        );
+        assert_eq!(
+            clamp_to_monitors(ivec2(-900, -50), size, &monitors),
+            Some(ivec2(0, 0))
+        );
+        // windows larger than the monitor are aligned with its top-left corner
+        assert_eq!(
+            clamp_to_monitors(ivec2(-10, 5000), ivec2(4000, 3000), &monitors),
+            Some(ivec2(0, 0))
+        );
+        // without any monitor there is nothing to clamp to
+        assert_eq!(clamp_to_monitors(ivec2(5000, 200), size, &[]), None);
+    }
+}